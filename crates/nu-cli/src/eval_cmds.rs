@@ -6,7 +6,7 @@ use nu_protocol::engine::Stack;
 use nu_protocol::report_error;
 use nu_protocol::{
     engine::{EngineState, StateWorkingSet},
-    PipelineData, Spanned, Value,
+    PipelineData, ShellError, Spanned, Value,
 };
 
 /// Run a command (or commands) given to us by the user
@@ -59,6 +59,9 @@ pub fn evaluate_commands(
             }
             crate::eval_file::print_table_or_error(engine_state, stack, pipeline_data, &mut config)
         }
+        Err(ShellError::Exit(_, code)) => {
+            std::process::exit(code);
+        }
         Err(err) => {
             let working_set = StateWorkingSet::new(engine_state);
 
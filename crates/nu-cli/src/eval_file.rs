@@ -140,6 +140,10 @@ pub fn evaluate_file(
                 return Ok(());
             }
 
+            Err(ShellError::Exit(_, code)) => {
+                std::process::exit(code);
+            }
+
             x => x,
         }
         .unwrap_or_else(|e| {
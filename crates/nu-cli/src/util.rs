@@ -292,6 +292,9 @@ pub fn eval_source(
                 let _ = enable_vt_processing();
             }
         }
+        Err(ShellError::Exit(_, code)) => {
+            std::process::exit(code);
+        }
         Err(err) => {
             set_last_exit_code(stack, 1);
 
@@ -5,7 +5,7 @@ use nu_protocol::{
     engine::{Command, EngineState, Stack},
     Category, Example, PipelineData, ShellError, Signature, Span, Type, Value,
 };
-use polars::prelude::{DatetimeMethods, IntoSeries};
+use polars::prelude::{DatetimeMethods, IntoSeries, Utf8Chunked};
 
 #[derive(Clone)]
 pub struct GetWeek;
@@ -25,24 +25,48 @@ impl Command for GetWeek {
                 Type::Custom("dataframe".into()),
                 Type::Custom("dataframe".into()),
             )
+            .switch(
+                "as-string",
+                "return ISO week labels like 2020-W32 instead of week numbers",
+                Some('s'),
+            )
             .category(Category::Custom("dataframe".into()))
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Returns week from a date",
-            example: r#"let dt = ('2020-08-04T16:39:18+00:00' | into datetime --timezone 'UTC');
+        vec![
+            Example {
+                description: "Returns week from a date",
+                example: r#"let dt = ('2020-08-04T16:39:18+00:00' | into datetime --timezone 'UTC');
     let df = ([$dt $dt] | dfr into-df);
     $df | dfr get-week"#,
-            result: Some(
-                NuDataFrame::try_from_columns(vec![Column::new(
-                    "0".to_string(),
-                    vec![Value::test_int(32), Value::test_int(32)],
-                )])
-                .expect("simple df for test should not fail")
-                .into_value(Span::test_data()),
-            ),
-        }]
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![Column::new(
+                        "0".to_string(),
+                        vec![Value::test_int(32), Value::test_int(32)],
+                    )])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::test_data()),
+                ),
+            },
+            Example {
+                description: "Returns ISO week labels from a date",
+                example: r#"let dt = ('2020-08-04T16:39:18+00:00' | into datetime --timezone 'UTC');
+    let df = ([$dt $dt] | dfr into-df);
+    $df | dfr get-week --as-string"#,
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![Column::new(
+                        "0".to_string(),
+                        vec![
+                            Value::test_string("2020-W32"),
+                            Value::test_string("2020-W32"),
+                        ],
+                    )])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::test_data()),
+                ),
+            },
+        ]
     }
 
     fn run(
@@ -75,7 +99,22 @@ fn command(
         )
     })?;
 
-    let res = casted.week().into_series();
+    let res = if call.has_flag("as-string") {
+        // Pair the ISO year with the week so labels are correct around year
+        // boundaries, where the ISO year differs from the calendar year.
+        let labels: Utf8Chunked = casted
+            .iso_year()
+            .into_iter()
+            .zip(casted.week().into_iter())
+            .map(|pair| match pair {
+                (Some(year), Some(week)) => Some(format!("{year}-W{week:02}")),
+                _ => None,
+            })
+            .collect();
+        labels.with_name(series.name()).into_series()
+    } else {
+        casted.week().into_series()
+    };
 
     NuDataFrame::try_from_series(vec![res], call.head)
         .map(|df| PipelineData::Value(NuDataFrame::into_value(df, call.head), None))
@@ -1,4 +1,4 @@
-use super::super::super::values::{Column, NuDataFrame};
+use super::super::super::values::{Column, NuDataFrame, NuExpression};
 
 use nu_protocol::{
     ast::Call,
@@ -19,12 +19,22 @@ impl Command for IsUnique {
         "Creates mask indicating unique values."
     }
 
+    fn extra_usage(&self) -> &str {
+        "For a multi-column dataframe, rows are compared as a whole: a row is unique based on all of its values, not per column."
+    }
+
     fn signature(&self) -> Signature {
         Signature::build(self.name())
-            .input_output_type(
-                Type::Custom("dataframe".into()),
-                Type::Custom("dataframe".into()),
-            )
+            .input_output_types(vec![
+                (
+                    Type::Custom("expression".into()),
+                    Type::Custom("expression".into()),
+                ),
+                (
+                    Type::Custom("dataframe".into()),
+                    Type::Custom("dataframe".into()),
+                ),
+            ])
             .category(Category::Custom("dataframe".into()))
     }
 
@@ -68,6 +78,11 @@ impl Command for IsUnique {
                     .into_value(Span::test_data()),
                 ),
             },
+            Example {
+                description: "Creates a is-unique expression from a column",
+                example: "dfr col a | dfr is-unique",
+                result: None,
+            },
         ]
     }
 
@@ -78,7 +93,19 @@ impl Command for IsUnique {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        command(engine_state, stack, call, input)
+        let value = input.into_value(call.head);
+        if NuDataFrame::can_downcast(&value) {
+            let df = NuDataFrame::try_from_value(value)?;
+            command(engine_state, stack, call, df)
+        } else {
+            let expr = NuExpression::try_from_value(value)?;
+            let expr: NuExpression = expr.into_polars().is_unique().into();
+
+            Ok(PipelineData::Value(
+                NuExpression::into_value(expr, call.head),
+                None,
+            ))
+        }
     }
 }
 
@@ -86,10 +113,8 @@ fn command(
     _engine_state: &EngineState,
     _stack: &mut Stack,
     call: &Call,
-    input: PipelineData,
+    df: NuDataFrame,
 ) -> Result<PipelineData, ShellError> {
-    let df = NuDataFrame::try_from_pipeline(input, call.head)?;
-
     let mut res = df
         .as_ref()
         .is_unique()
@@ -1,9 +1,12 @@
-use nu_engine::{eval_block, eval_expression, eval_expression_with_input, CallExt};
+use nu_engine::{
+    eval_block, eval_expression, eval_expression_with_input, expression_references_in, CallExt,
+};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Block, Command, EngineState, Stack, StateWorkingSet};
 use nu_protocol::eval_const::{eval_const_subexpression, eval_constant, eval_constant_with_input};
 use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+    IN_VARIABLE_ID,
 };
 
 #[derive(Clone)]
@@ -106,7 +109,26 @@ impl Command for If {
         let then_block: Block = call.req(engine_state, stack, 1)?;
         let else_case = call.positional_nth(2);
 
-        let result = eval_expression(engine_state, stack, cond)?;
+        // A condition referencing `$in` sees the pipeline input
+        // (`$data | if ($in | is-empty) {...}`). The input has to be
+        // collected and bound before the condition runs, and is handed to
+        // the chosen branch afterwards; conditions that don't mention `$in`
+        // leave streaming input untouched.
+        let binds_input = expression_references_in(engine_state, cond);
+        let input = if binds_input {
+            let metadata = input.metadata();
+            let value = input.into_value(call.head);
+            stack.add_var(IN_VARIABLE_ID, value.clone());
+            PipelineData::Value(value, metadata)
+        } else {
+            input
+        };
+
+        let result = eval_expression(engine_state, stack, cond);
+        if binds_input {
+            stack.remove_var(IN_VARIABLE_ID);
+        }
+        let result = result?;
         match &result {
             Value::Bool { val, .. } => {
                 if *val {
@@ -20,7 +20,11 @@ impl Command for Return {
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("return")
             .input_output_types(vec![(Type::Nothing, Type::Any)])
-            .optional("return_value", SyntaxShape::Any, "optional value to return")
+            .rest(
+                "return_value",
+                SyntaxShape::Any,
+                "optional value(s) to return; several values are returned as a list",
+            )
             .category(Category::Core)
     }
 
@@ -40,22 +44,29 @@ impl Command for Return {
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let return_value: Option<Value> = call.opt(engine_state, stack, 0)?;
-        if let Some(value) = return_value {
-            Err(ShellError::Return(call.head, Box::new(value)))
-        } else {
-            Err(ShellError::Return(
-                call.head,
-                Box::new(Value::nothing(call.head)),
-            ))
-        }
+        let mut return_values: Vec<Value> = call.rest(engine_state, stack, 0)?;
+        // A single value stays a scalar; several values are packaged as a
+        // list, which `eval_block_with_early_return` turns into pipeline data.
+        let value = match return_values.len() {
+            0 => Value::nothing(call.head),
+            1 => return_values.remove(0),
+            _ => Value::list(return_values, call.head),
+        };
+        Err(ShellError::Return(call.head, Box::new(value)))
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Return early",
-            example: r#"def foo [] { return }"#,
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Return early",
+                example: r#"def foo [] { return }"#,
+                result: None,
+            },
+            Example {
+                description: "Return several values as a list",
+                example: r#"def foo [] { return 1 2 }"#,
+                result: None,
+            },
+        ]
     }
 }
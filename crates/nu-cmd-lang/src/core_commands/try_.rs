@@ -65,10 +65,28 @@ impl Command for Try {
             Ok(pipeline) => {
                 let (pipeline, external_failed) = pipeline.is_external_failed();
                 if external_failed {
-                    // Because external command errors aren't "real" errors,
-                    // (unless do -c is in effect)
-                    // they can't be passed in as Nushell values.
-                    let err_value = Value::nothing(call.head);
+                    // External failures aren't "real" errors (unless do -c is
+                    // in effect), so build a structured one from the exit code
+                    // and whatever stderr was captured.
+                    let (exit_code, stderr) = external_failure_parts(pipeline);
+                    let error = ShellError::ExternalCommandFailed {
+                        exit_code,
+                        stderr: stderr.clone(),
+                        span: call.head,
+                    };
+                    let err_value = Value::record(
+                        record! {
+                            "msg" => Value::string(error.to_string(), call.head),
+                            "debug" => Value::string(format!("{error:?}"), call.head),
+                            "exit_code" => Value::int(exit_code, call.head),
+                            "stderr" => match stderr {
+                                Some(stderr) => Value::string(stderr, call.head),
+                                None => Value::nothing(call.head),
+                            },
+                            "raw" => Value::error(error, call.head),
+                        },
+                        call.head,
+                    );
                     handle_catch(err_value, catch_block, engine_state, stack)
                 } else {
                     Ok(pipeline)
@@ -122,7 +140,32 @@ fn handle_catch(
     }
 }
 
-/// The flow control commands `break`/`continue`/`return` emit their own [`ShellError`] variants
+/// Pull the exit code and buffered stderr out of a failed external stream, so
+/// the catch block receives a structured error instead of nothing. By this
+/// point `is_external_failed` has already buffered both streams, so consuming
+/// them here loses no output.
+fn external_failure_parts(pipeline: PipelineData) -> (i64, Option<String>) {
+    if let PipelineData::ExternalStream {
+        stderr, exit_code, ..
+    } = pipeline
+    {
+        let exit_code = exit_code
+            .and_then(|stream| stream.into_iter().last())
+            .and_then(|value| value.as_int().ok())
+            .unwrap_or(1);
+        let stderr = stderr.and_then(|stream| {
+            stream
+                .into_bytes()
+                .ok()
+                .map(|bytes| String::from_utf8_lossy(&bytes.item).to_string())
+        });
+        (exit_code, stderr)
+    } else {
+        (1, None)
+    }
+}
+
+/// The flow control commands `break`/`continue`/`return`/`exit` emit their own [`ShellError`] variants
 /// We need to ignore those in `try` and bubble them through
 ///
 /// `Err` when flow control to bubble up with `?`
@@ -131,6 +174,7 @@ fn intercept_block_control(error: ShellError) -> Result<ShellError, ShellError>
         nu_protocol::ShellError::Break(_) => Err(error),
         nu_protocol::ShellError::Continue(_) => Err(error),
         nu_protocol::ShellError::Return(_, _) => Err(error),
+        nu_protocol::ShellError::Exit(_, _) => Err(error),
         _ => Ok(error),
     }
 }
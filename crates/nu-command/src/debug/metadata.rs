@@ -86,6 +86,12 @@ impl Command for Metadata {
                         PipelineMetadata {
                             data_source: DataSource::HtmlThemes,
                         } => record.push("source", Value::string("into html --list", head)),
+                        PipelineMetadata {
+                            data_source: DataSource::FilePath,
+                        } => record.push("source", Value::string("path literal", head)),
+                        PipelineMetadata {
+                            data_source: DataSource::Command { name, .. },
+                        } => record.push("source", Value::string(name.clone(), head)),
                     }
                 }
 
@@ -133,6 +139,12 @@ fn build_metadata_record(arg: &Value, metadata: Option<&PipelineMetadata>, head:
             PipelineMetadata {
                 data_source: DataSource::HtmlThemes,
             } => record.push("source", Value::string("into html --list", head)),
+            PipelineMetadata {
+                data_source: DataSource::FilePath,
+            } => record.push("source", Value::string("path literal", head)),
+            PipelineMetadata {
+                data_source: DataSource::Command { name, .. },
+            } => record.push("source", Value::string(name.clone(), head)),
         }
     }
 
@@ -48,9 +48,7 @@ impl Command for ViewSource {
                     let vec_of_flags = &sig.named;
                     // gets vector of positionals.
                     if let Some(block_id) = decl.get_block_id() {
-                        let block = engine_state.get_block(block_id);
-                        if let Some(block_span) = block.span {
-                            let contents = engine_state.get_span_contents(block_span);
+                        if let Some(contents) = engine_state.get_block_source(block_id) {
                             // name of function
                             let mut final_contents = format!("def {val} [ ");
                             for n in vec_of_required {
@@ -82,7 +80,7 @@ impl Command for ViewSource {
                                 );
                             }
                             final_contents.push_str("] ");
-                            final_contents.push_str(&String::from_utf8_lossy(contents));
+                            final_contents.push_str(&contents);
                             Ok(Value::string(final_contents, call.head).into_pipeline_data())
                         } else {
                             Err(ShellError::GenericError(
@@ -130,12 +128,8 @@ impl Command for ViewSource {
             }
             value => {
                 if let Ok(block_id) = value.as_block() {
-                    let block = engine_state.get_block(block_id);
-
-                    if let Some(span) = block.span {
-                        let contents = engine_state.get_span_contents(span);
-                        Ok(Value::string(String::from_utf8_lossy(contents), call.head)
-                            .into_pipeline_data())
+                    if let Some(contents) = engine_state.get_block_source(block_id) {
+                        Ok(Value::string(contents, call.head).into_pipeline_data())
                     } else {
                         Ok(Value::string("<internal command>", call.head).into_pipeline_data())
                     }
@@ -316,19 +316,36 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
         bind_command! {
             Math,
             MathAbs,
+            MathAccumulate,
             MathAvg,
+            MathBucketize,
             MathCeil,
+            MathCovariance,
+            MathCross,
+            MathDot,
+            MathEntropy,
             MathFloor,
+            MathFrequency,
+            MathInterp,
+            MathLerp,
             MathMax,
             MathMedian,
             MathMin,
             MathMode,
+            MathMovingAverage,
+            MathNormalize,
+            MathOutliers,
             MathProduct,
+            MathRank,
             MathRound,
+            MathRunningMedian,
+            MathSign,
             MathSqrt,
             MathStddev,
             MathSum,
             MathVariance,
+            MathWeightedAvg,
+            MathZscore,
             MathLog,
         };
 
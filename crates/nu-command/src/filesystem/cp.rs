@@ -28,6 +28,10 @@ impl Command for Cp {
         "cp-old"
     }
 
+    fn is_destructive(&self) -> bool {
+        true
+    }
+
     fn usage(&self) -> &str {
         "Old nushell version of Copy files."
     }
@@ -17,6 +17,10 @@ impl Command for Mkdir {
         "mkdir"
     }
 
+    fn is_destructive(&self) -> bool {
+        true
+    }
+
     fn signature(&self) -> Signature {
         Signature::build("mkdir")
             .input_output_types(vec![(Type::Nothing, Type::Nothing)])
@@ -19,6 +19,10 @@ impl Command for Mv {
         "mv"
     }
 
+    fn is_destructive(&self) -> bool {
+        true
+    }
+
     fn usage(&self) -> &str {
         "Move files or directories."
     }
@@ -30,6 +30,10 @@ impl Command for Rm {
         "rm"
     }
 
+    fn is_destructive(&self) -> bool {
+        true
+    }
+
     fn usage(&self) -> &str {
         "Remove files and directories."
     }
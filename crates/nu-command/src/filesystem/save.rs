@@ -22,6 +22,10 @@ impl Command for Save {
         "save"
     }
 
+    fn is_destructive(&self) -> bool {
+        true
+    }
+
     fn usage(&self) -> &str {
         "Save a file."
     }
@@ -19,6 +19,10 @@ impl Command for Touch {
         "touch"
     }
 
+    fn is_destructive(&self) -> bool {
+        true
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["create", "file"]
     }
@@ -25,6 +25,10 @@ impl Command for UCp {
         "cp"
     }
 
+    fn is_destructive(&self) -> bool {
+        true
+    }
+
     fn usage(&self) -> &str {
         "Copy files using uutils/coreutils cp."
     }
@@ -19,6 +19,10 @@ impl Command for UMkdir {
         "umkdir"
     }
 
+    fn is_destructive(&self) -> bool {
+        true
+    }
+
     fn usage(&self) -> &str {
         "Create directories, with intermediary directories if required using uutils/coreutils mkdir."
     }
@@ -134,7 +134,8 @@ with 'transpose' first."#
             | PipelineData::Value(Value::List { .. }, ..)
             | PipelineData::ListStream { .. } => Ok(input
                 .into_iter()
-                .map_while(move |x| {
+                .enumerate()
+                .map_while(move |(idx, x)| {
                     // with_env() is used here to ensure that each iteration uses
                     // a different set of environment variables.
                     // Hence, a 'cd' in the first loop won't affect the next loop.
@@ -145,6 +146,7 @@ with 'transpose' first."#
                             stack.add_var(*var_id, x.clone());
                         }
                     }
+                    stack.add_loop_index(idx as i64, span);
 
                     let input_span = x.span();
                     let x_is_error = x.is_error();
@@ -172,7 +174,8 @@ with 'transpose' first."#
                 ..
             } => Ok(stream
                 .into_iter()
-                .map_while(move |x| {
+                .enumerate()
+                .map_while(move |(idx, x)| {
                     // with_env() is used here to ensure that each iteration uses
                     // a different set of environment variables.
                     // Hence, a 'cd' in the first loop won't affect the next loop.
@@ -190,6 +193,7 @@ with 'transpose' first."#
                             stack.add_var(*var_id, x.clone());
                         }
                     }
+                    stack.add_loop_index(idx as i64, span);
 
                     let input_span = x.span();
                     let x_is_error = x.is_error();
@@ -220,6 +224,7 @@ with 'transpose' first."#
                         stack.add_var(*var_id, x.clone());
                     }
                 }
+                stack.add_loop_index(0, span);
 
                 eval_block_with_early_return(
                     &engine_state,
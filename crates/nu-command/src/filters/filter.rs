@@ -73,7 +73,8 @@ a variable. On the other hand, the "row condition" syntax is not supported."#
                 // To enumerate over the input (for the index argument),
                 // it must be converted into an iterator using into_iter().
                 .into_iter()
-                .filter_map(move |x| {
+                .enumerate()
+                .filter_map(move |(idx, x)| {
                     // with_env() is used here to ensure that each iteration uses
                     // a different set of environment variables.
                     // Hence, a 'cd' in the first loop won't affect the next loop.
@@ -84,6 +85,7 @@ a variable. On the other hand, the "row condition" syntax is not supported."#
                             stack.add_var(*var_id, x.clone());
                         }
                     }
+                    stack.add_loop_index(idx as i64, span);
 
                     match eval_block(
                         &engine_state,
@@ -114,7 +116,8 @@ a variable. On the other hand, the "row condition" syntax is not supported."#
                 ..
             } => Ok(stream
                 .into_iter()
-                .filter_map(move |x| {
+                .enumerate()
+                .filter_map(move |(idx, x)| {
                     // see note above about with_env()
                     stack.with_env(&orig_env_vars, &orig_env_hidden);
 
@@ -128,6 +131,7 @@ a variable. On the other hand, the "row condition" syntax is not supported."#
                             stack.add_var(*var_id, x.clone());
                         }
                     }
+                    stack.add_loop_index(idx as i64, span);
 
                     match eval_block(
                         &engine_state,
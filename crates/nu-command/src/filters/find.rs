@@ -1,16 +1,19 @@
+use crate::formats::from::json::convert_string_to_value;
 use crate::help::highlight_search_string;
 use itertools::Itertools;
 
 use fancy_regex::Regex;
+use rayon::prelude::*;
 use nu_ansi_term::Style;
-use nu_color_config::StyleComputer;
-use nu_engine::CallExt;
+use nu_color_config::{lookup_ansi_color_style, StyleComputer};
+use nu_engine::{current_dir, CallExt};
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
     record, Category, Config, Example, IntoInterruptiblePipelineData, IntoPipelineData, ListStream,
-    PipelineData, Record, ShellError, Signature, Span, SyntaxShape, Type, Value,
+    PipelineData, Record, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
+use std::collections::HashSet;
 
 #[derive(Clone)]
 pub struct Find;
@@ -42,6 +45,12 @@ impl Command for Find {
                 "regex to match with",
                 Some('r'),
             )
+            .named(
+                "regex-file",
+                SyntaxShape::Filepath,
+                "read regex patterns from a file, one per line, matched as alternatives; blank lines and # comments are skipped",
+                None,
+            )
             .switch(
                 "ignore-case",
                 "case-insensitive regex mode; equivalent to (?i)",
@@ -63,7 +72,96 @@ impl Command for Find {
                 "column names to be searched (with rest parameter, not regex yet)",
                 Some('c'),
             )
+            .named(
+                "highlight-color",
+                SyntaxShape::String,
+                "color name or ansi spec used to highlight matches, instead of the search_result theme color",
+                None,
+            )
             .switch("invert", "invert the match", Some('v'))
+            .switch(
+                "invert-keep-structure",
+                "with --regex, keep every row and mask matched substrings in string cells instead of dropping rows",
+                None,
+            )
+            .named(
+                "mask",
+                SyntaxShape::String,
+                "replacement written over matched substrings with --invert-keep-structure (default '****')",
+                None,
+            )
+            .named(
+                "query",
+                SyntaxShape::String,
+                "keep rows where this query is truthy: paths, comparisons and and/or, e.g. '.items[].status == \"error\"'",
+                Some('q'),
+            )
+            .named(
+                "col-ref",
+                SyntaxShape::Record(vec![]),
+                "keep rows where each given column equals the referenced column, e.g. {actual: expected}",
+                None,
+            )
+            .named(
+                "before-context",
+                SyntaxShape::Int,
+                "also emit this many rows before each matching row; overlapping windows are merged (buffers list input)",
+                Some('B'),
+            )
+            .named(
+                "threads",
+                SyntaxShape::Int,
+                "the number of threads to match with; buffers list input instead of streaming",
+                Some('t'),
+            )
+            .named(
+                "max-depth",
+                SyntaxShape::Int,
+                "limit how deep to match into nested records and lists (0 matches top-level cells only; default unlimited)",
+                None,
+            )
+            .switch(
+                "parse-json",
+                "try to parse string cells as JSON before matching, so terms can match structured fields",
+                None,
+            )
+            .switch(
+                "whole-record",
+                "match terms against the record rendered as one string instead of per cell",
+                None,
+            )
+            .switch(
+                "slurp",
+                "buffer the whole input and match it as one string instead of line by line (holds the entire stream in memory)",
+                None,
+            )
+            .switch(
+                "summary",
+                "output a record of per-term hit counts instead of the matching rows",
+                None,
+            )
+            .switch(
+                "count-matches",
+                "output the total number of individual term or regex occurrences across the whole input as a single int",
+                None,
+            )
+            .switch(
+                "as-table",
+                "for external stream input, output records of {line_number, text} instead of plain lines; with --before-context, records of {row_number, match, row}",
+                None,
+            )
+            .named(
+                "terms",
+                SyntaxShape::List(Box::new(SyntaxShape::Any)),
+                "additional terms to search, merged with the rest arguments",
+                None,
+            )
+            .named(
+                "terms-file",
+                SyntaxShape::Filepath,
+                "read search terms from a file, one per line; blank lines and # comments are skipped",
+                None,
+            )
             .rest("rest", SyntaxShape::Any, "terms to search")
             .category(Category::Filters)
     }
@@ -74,6 +172,34 @@ impl Command for Find {
 
     fn examples(&self) -> Vec<Example> {
         vec![
+            Example {
+                description: "Keep rows matching a structured query",
+                example: r#"[[status]; [error] [ok]] | find --query '.status == "error"'"#,
+                result: Some(Value::test_list(vec![Value::test_record(record! {
+                    "status" => Value::test_string("error"),
+                })])),
+            },
+            Example {
+                description: "Keep rows where one column's value equals another's",
+                example: "[[actual expected]; [1 1] [1 2]] | find --col-ref {actual: expected}",
+                result: Some(Value::test_list(vec![Value::test_record(record! {
+                    "actual" => Value::test_int(1),
+                    "expected" => Value::test_int(1),
+                })])),
+            },
+            Example {
+                description: "Keep a row of context before each match",
+                example: "[1 2 3 4] | find 3 --before-context 1",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(2),
+                    Value::test_int(3),
+                ])),
+            },
+            Example {
+                description: "Count every occurrence of a term across the input",
+                example: "[abc abba b] | find b --count-matches",
+                result: Some(Value::test_int(4)),
+            },
             Example {
                 description: "Search for multiple terms in a command output",
                 example: r#"ls | find toml md sh"#,
@@ -111,6 +237,18 @@ impl Command for Find {
                     Span::test_data(),
                 )),
             },
+            Example {
+                description: "Mask matches in place instead of dropping rows",
+                example: r#"[[card]; ['4111111111111111'] [none]] | find --regex '\d{16}' --invert-keep-structure"#,
+                result: Some(Value::test_list(vec![
+                    Value::test_record(record! {
+                        "card" => Value::test_string("****"),
+                    }),
+                    Value::test_record(record! {
+                        "card" => Value::test_string("none"),
+                    }),
+                ])),
+            },
             Example {
                 description: "Find using regex case insensitive",
                 example: r#"[aBc bde Arc abf] | find --regex "ab" -i"#,
@@ -206,64 +344,269 @@ impl Command for Find {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let regex = call.get_flag::<String>(engine_state, stack, "regex")?;
+        let regex = regex_with_file_patterns(regex, engine_state, stack, call)?;
+        let query: Option<Spanned<String>> = call.get_flag(engine_state, stack, "query")?;
+        let col_ref: Option<Value> = call.get_flag(engine_state, stack, "col-ref")?;
 
-        if let Some(regex) = regex {
+        if let Some(col_ref) = col_ref {
+            find_with_col_ref(col_ref, engine_state, call, input)
+        } else if let Some(query) = query {
+            find_with_query(query, engine_state, call, input)
+        } else if let Some(regex) = regex {
             find_with_regex(regex, engine_state, stack, call, input)
         } else {
-            let input = split_string_if_multiline(input, call.head);
-            find_with_rest_and_highlight(engine_state, stack, call, input)
+            let input = if call.has_flag("slurp") {
+                slurp_input(input, call.head)
+            } else {
+                split_string_if_multiline(input, call.head)
+            };
+            if call.has_flag("summary") {
+                find_with_summary(engine_state, stack, call, input)
+            } else if call.has_flag("count-matches") {
+                find_count_matches(engine_state, stack, call, input)
+            } else {
+                find_with_rest_and_highlight(engine_state, stack, call, input)
+            }
         }
     }
 }
 
+/// Read the non-empty, non-`#`-comment lines of a file, resolving the path
+/// against the current working directory. Shared by `--terms-file` and
+/// `--regex-file`, which exist so big term sets don't have to be shell-quoted
+/// inline.
+fn read_line_entries(
+    file: &Spanned<String>,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+) -> Result<Vec<String>, ShellError> {
+    let cwd = current_dir(engine_state, stack)?;
+    let path = nu_path::expand_path_with(&file.item, cwd);
+    let contents = std::fs::read_to_string(&path).map_err(|err| {
+        ShellError::FileNotFoundCustom(format!("Could not read {}: {err}", path.display()), file.span)
+    })?;
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect())
+}
+
+/// Collect search terms from the rest arguments, then `--terms`, then
+/// `--terms-file`, so the file-based paths feed the same term set `call.rest`
+/// populates today.
+fn gather_terms(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<Vec<Value>, ShellError> {
+    let mut terms = call.rest::<Value>(engine_state, stack, 0)?;
+    if let Some(list) = call.get_flag::<Vec<Value>>(engine_state, stack, "terms")? {
+        terms.extend(list);
+    }
+    if let Some(file) = call.get_flag::<Spanned<String>>(engine_state, stack, "terms-file")? {
+        let span = file.span;
+        terms.extend(
+            read_line_entries(&file, engine_state, stack)?
+                .into_iter()
+                .map(|line| Value::string(line, span)),
+        );
+    }
+    Ok(terms)
+}
+
+/// Merge `--regex` with the patterns read from `--regex-file` into a single
+/// alternation, so a row matches when any pattern does.
+fn regex_with_file_patterns(
+    regex: Option<String>,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<Option<String>, ShellError> {
+    let regex_file: Option<Spanned<String>> = call.get_flag(engine_state, stack, "regex-file")?;
+    let Some(file) = regex_file else {
+        return Ok(regex);
+    };
+    let mut patterns: Vec<String> = regex.into_iter().collect();
+    patterns.extend(read_line_entries(&file, engine_state, stack)?);
+    match patterns.len() {
+        0 => Err(ShellError::GenericError(
+            "No patterns found".into(),
+            "the file contains no patterns, only blank lines or comments".into(),
+            Some(file.span),
+            None,
+            vec![],
+        )),
+        // a lone pattern is passed through untouched, exactly as --regex would
+        1 => Ok(patterns.pop()),
+        _ => Ok(Some(
+            patterns
+                .iter()
+                .map(|pattern| format!("(?:{pattern})"))
+                .join("|"),
+        )),
+    }
+}
+
 fn find_with_regex(
     regex: String,
     engine_state: &EngineState,
-    _stack: &mut Stack,
+    stack: &mut Stack,
     call: &Call,
     input: PipelineData,
 ) -> Result<PipelineData, ShellError> {
     let span = call.head;
     let ctrlc = engine_state.ctrlc.clone();
     let config = engine_state.get_config().clone();
+    let threads: Option<usize> = call.get_flag(engine_state, stack, "threads")?;
 
     let insensitive = call.has_flag("ignore-case");
     let multiline = call.has_flag("multiline");
     let dotall = call.has_flag("dotall");
     let invert = call.has_flag("invert");
 
-    let flags = match (insensitive, multiline, dotall) {
-        (false, false, false) => "",
-        (true, false, false) => "(?i)", // case insensitive
-        (false, true, false) => "(?m)", // multi-line mode
-        (false, false, true) => "(?s)", // allow . to match \n
-        (true, true, false) => "(?im)", // case insensitive and multi-line mode
-        (true, false, true) => "(?is)", // case insensitive and allow . to match \n
-        (false, true, true) => "(?ms)", // multi-line mode and allow . to match \n
-        (true, true, true) => "(?ims)", // case insensitive, multi-line mode and allow . to match \n
-    };
+    // The switches are composed as defaults scoped around the pattern
+    // (`(?i:pat)`) instead of rewriting the pattern itself, so inline flags a
+    // pattern carries are left alone and override from their position on:
+    // `(?-i)Exact` inside a pattern beats a command-level `-i`, per pattern
+    // even within an alternation assembled from --regex-file.
+    let mut flags = String::new();
+    if insensitive {
+        flags.push('i'); // case insensitive
+    }
+    if multiline {
+        flags.push('m'); // multi-line mode
+    }
+    if dotall {
+        flags.push('s'); // allow . to match \n
+    }
 
-    let regex = flags.to_string() + regex.as_str();
+    let regex = if flags.is_empty() {
+        regex
+    } else {
+        format!("(?{flags}:{regex})")
+    };
 
     let re = Regex::new(regex.as_str()).map_err(|e| ShellError::TypeMismatch {
         err_message: format!("invalid regex: {e}"),
         span,
     })?;
 
+    // `--count-matches` sums individual occurrences instead of filtering rows,
+    // like summing `grep -c` over the input.
+    if call.has_flag("count-matches") {
+        let mut total: i64 = 0;
+        for value in input {
+            total += count_regex_matches(&value, &re, &config);
+        }
+        return Ok(Value::int(total, span).into_pipeline_data());
+    }
+
+    // `--invert-keep-structure` redacts instead of filtering: every row is
+    // kept, and matched substrings inside string cells are overwritten with
+    // the mask. Typed non-string cells pass through untouched.
+    if call.has_flag("invert-keep-structure") {
+        let mask = call
+            .get_flag::<String>(engine_state, stack, "mask")?
+            .unwrap_or_else(|| "****".to_string());
+        return input.map(move |value| mask_matches(value, &re, &mask), ctrlc);
+    }
+
+    // Matching is CPU-bound, so `--threads` trades streaming for a buffered,
+    // order-preserving parallel filter.
+    if let Some(threads) = threads {
+        let pool = create_pool(threads)?;
+        let values: Vec<Value> = input.into_iter().collect();
+        let output: Vec<Value> = pool.install(|| {
+            values
+                .into_par_iter()
+                .filter(|value| value_matches_regex(value, &re, &config, invert))
+                .collect()
+        });
+        return Ok(output.into_pipeline_data(ctrlc));
+    }
+
     input.filter(
-        move |value| match value {
-            Value::String { val, .. } => re.is_match(val.as_str()).unwrap_or(false) != invert,
-            Value::Record {
-                val: Record { vals, .. },
-                ..
-            }
-            | Value::List { vals, .. } => values_match_find(vals, &re, &config, invert),
-            _ => false,
-        },
+        move |value| value_matches_regex(value, &re, &config, invert),
         ctrlc,
     )
 }
 
+/// Total number of regex matches inside a value, recursing into records and
+/// lists; other cells are counted against their rendered string.
+fn count_regex_matches(value: &Value, re: &Regex, config: &Config) -> i64 {
+    match value {
+        Value::Record { val, .. } => val
+            .vals
+            .iter()
+            .map(|val| count_regex_matches(val, re, config))
+            .sum(),
+        Value::List { vals, .. } => vals
+            .iter()
+            .map(|val| count_regex_matches(val, re, config))
+            .sum(),
+        value => re
+            .find_iter(&value.into_string(" ", config))
+            .filter(|m| m.is_ok())
+            .count() as i64,
+    }
+}
+
+/// Overwrite every regex match inside string cells with the mask, recursing
+/// into records and lists. Non-string cells are returned unchanged.
+fn mask_matches(value: Value, re: &Regex, mask: &str) -> Value {
+    let span = value.span();
+    match value {
+        Value::String { val, .. } => Value::string(re.replace_all(&val, mask).into_owned(), span),
+        Value::Record { val, .. } => Value::record(
+            Record {
+                cols: val.cols,
+                vals: val
+                    .vals
+                    .into_iter()
+                    .map(|val| mask_matches(val, re, mask))
+                    .collect(),
+            },
+            span,
+        ),
+        Value::List { vals, .. } => Value::list(
+            vals.into_iter()
+                .map(|val| mask_matches(val, re, mask))
+                .collect(),
+            span,
+        ),
+        other => other,
+    }
+}
+
+fn value_matches_regex(value: &Value, re: &Regex, config: &Config, invert: bool) -> bool {
+    match value {
+        Value::String { val, .. } => re.is_match(val.as_str()).unwrap_or(false) != invert,
+        Value::Record {
+            val: Record { vals, .. },
+            ..
+        }
+        | Value::List { vals, .. } => values_match_find(vals, re, config, invert),
+        _ => false,
+    }
+}
+
+fn create_pool(num_threads: usize) -> Result<rayon::ThreadPool, ShellError> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .map_err(|e| {
+            ShellError::GenericError(
+                "Error creating thread pool".into(),
+                e.to_string(),
+                Some(Span::unknown()),
+                None,
+                Vec::new(),
+            )
+        })
+}
+
 fn values_match_find(values: &[Value], re: &Regex, config: &Config, invert: bool) -> bool {
     match invert {
         true => !record_matches_regex(values, re, config),
@@ -330,6 +673,108 @@ fn highlight_terms_in_record_with_search_columns(
     )
 }
 
+/// `--summary`: tally, per term, how many input rows that term matches on its
+/// own, using the same matching rules as the filtering path. `--invert` flips
+/// each per-term count to the rows the term does not match.
+fn find_with_summary(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let config = engine_state.get_config().clone();
+    let invert = call.has_flag("invert");
+    let whole_record = call.has_flag("whole-record");
+    let parse_json = call.has_flag("parse-json");
+    let max_depth: Option<i64> = call.get_flag(engine_state, stack, "max-depth")?;
+    let terms = gather_terms(engine_state, stack, call)?;
+    let columns_to_search: Vec<String> = call
+        .get_flag(engine_state, stack, "columns")?
+        .unwrap_or_default();
+
+    let values: Vec<Value> = input.into_iter().collect();
+
+    let mut summary = Record::new();
+    for term in terms {
+        let lower_term = Value::string(term.into_string("", &config).to_lowercase(), span);
+        let count = values
+            .iter()
+            .filter(|value| {
+                value_should_be_printed(
+                    value,
+                    &config,
+                    std::slice::from_ref(&lower_term),
+                    std::slice::from_ref(&term),
+                    span,
+                    &columns_to_search,
+                    invert,
+                    whole_record,
+                    parse_json,
+                    max_depth,
+                )
+            })
+            .count();
+        summary.push(term.into_string("", &config), Value::int(count as i64, span));
+    }
+
+    Ok(Value::record(summary, span).into_pipeline_data())
+}
+
+/// `--count-matches` for the term path: the total number of individual
+/// substring occurrences across the whole input, counted case-insensitively
+/// per term and per searched cell, honoring `--columns` at the top level.
+fn find_count_matches(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let config = engine_state.get_config().clone();
+    let terms = gather_terms(engine_state, stack, call)?;
+    let lower_terms: Vec<String> = terms
+        .iter()
+        .map(|term| term.into_string("", &config).to_lowercase())
+        .collect();
+    let columns_to_search: Vec<String> = call
+        .get_flag(engine_state, stack, "columns")?
+        .unwrap_or_default();
+
+    let mut total: i64 = 0;
+    for value in input {
+        total += count_term_occurrences(&value, &lower_terms, &columns_to_search, &config);
+    }
+    Ok(Value::int(total, span).into_pipeline_data())
+}
+
+fn count_term_occurrences(
+    value: &Value,
+    lower_terms: &[String],
+    columns_to_search: &[String],
+    config: &Config,
+) -> i64 {
+    match value {
+        Value::Record { val, .. } => val
+            .iter()
+            .filter(|(col, _)| columns_to_search.is_empty() || columns_to_search.contains(col))
+            .map(|(_, val)| count_term_occurrences(val, lower_terms, &[], config))
+            .sum(),
+        Value::List { vals, .. } => vals
+            .iter()
+            .map(|val| count_term_occurrences(val, lower_terms, &[], config))
+            .sum(),
+        value => {
+            let rendered = value.into_string(" ", config).to_lowercase();
+            lower_terms
+                .iter()
+                .filter(|term| !term.is_empty())
+                .map(|term| rendered.matches(term.as_str()).count() as i64)
+                .sum()
+        }
+    }
+}
+
 fn contains_ignore_case(string: &str, substring: &str) -> bool {
     string.to_lowercase().contains(&substring.to_lowercase())
 }
@@ -346,11 +791,16 @@ fn find_with_rest_and_highlight(
     let config = engine_state.get_config().clone();
     let filter_config = engine_state.get_config().clone();
     let invert = call.has_flag("invert");
-    let terms = call.rest::<Value>(&engine_state, stack, 0)?;
+    let whole_record = call.has_flag("whole-record");
+    let parse_json = call.has_flag("parse-json");
+    let as_table = call.has_flag("as-table");
+    let max_depth: Option<i64> = call.get_flag(&engine_state, stack, "max-depth")?;
+    let terms = gather_terms(&engine_state, stack, call)?;
     let lower_terms = terms
         .iter()
         .map(|v| Value::string(v.into_string("", &config).to_lowercase(), span))
         .collect::<Vec<Value>>();
+    let filter_terms = terms.clone();
 
     let style_computer = StyleComputer::from_config(&engine_state, stack);
     // Currently, search results all use the same style.
@@ -358,7 +808,13 @@ fn find_with_rest_and_highlight(
     // defined for "string").
     let string_style = style_computer.compute("string", &Value::string("search result", span));
     let highlight_style =
-        style_computer.compute("search_result", &Value::string("search result", span));
+        match call.get_flag::<String>(&engine_state, stack, "highlight-color")? {
+            // per-invocation override, e.g. to tell apart several `find`s in
+            // one composed view; unknown color names fall back to the default
+            // style, like elsewhere in the config
+            Some(color) => lookup_ansi_color_style(&color),
+            None => style_computer.compute("search_result", &Value::string("search result", span)),
+        };
 
     let cols_to_search_in_map = match call.get_flag(&engine_state, stack, "columns")? {
         Some(cols) => cols,
@@ -367,6 +823,131 @@ fn find_with_rest_and_highlight(
 
     let cols_to_search_in_filter = cols_to_search_in_map.clone();
 
+    // `--threads` buffers list input and matches in parallel, preserving order
+    let threads: Option<usize> = call.get_flag(&engine_state, stack, "threads")?;
+    let input = match (threads, input) {
+        (Some(threads), input @ (PipelineData::Value(..) | PipelineData::ListStream(..))) => {
+            let pool = create_pool(threads)?;
+            let metadata = input.metadata();
+            let values: Vec<Value> = input.into_iter().collect();
+            let output: Vec<Value> = pool.install(|| {
+                values
+                    .into_par_iter()
+                    .map(|mut x| {
+                        let span = x.span();
+                        match &mut x {
+                            Value::Record { val, .. } => {
+                                highlight_terms_in_record_with_search_columns(
+                                    &cols_to_search_in_map,
+                                    val,
+                                    span,
+                                    &config,
+                                    &terms,
+                                    string_style,
+                                    highlight_style,
+                                )
+                            }
+                            _ => x,
+                        }
+                    })
+                    .filter(|value| {
+                        value_should_be_printed(
+                            value,
+                            &filter_config,
+                            &lower_terms,
+                            &filter_terms,
+                            span,
+                            &cols_to_search_in_filter,
+                            invert,
+                            whole_record,
+                            parse_json,
+                            max_depth,
+                        )
+                    })
+                    .collect()
+            });
+            return Ok(output.into_pipeline_data(ctrlc).set_metadata(metadata));
+        }
+        (_, input) => input,
+    };
+
+    // `--before-context` needs lookback over row indices, so list input is
+    // buffered like the `--threads` path instead of streamed.
+    let before_context: Option<Spanned<i64>> =
+        call.get_flag(&engine_state, stack, "before-context")?;
+    if let Some(before) = before_context {
+        if before.item < 0 {
+            return Err(ShellError::IncorrectValue {
+                msg: "before-context cannot be negative".into(),
+                val_span: before.span,
+                call_span: span,
+            });
+        }
+        let before = before.item as usize;
+        let metadata = input.metadata();
+        let values: Vec<Value> = input.into_iter().collect();
+
+        let highlight = |mut x: Value| {
+            let value_span = x.span();
+            match &mut x {
+                Value::Record { val, .. } => highlight_terms_in_record_with_search_columns(
+                    &cols_to_search_in_map,
+                    val,
+                    value_span,
+                    &config,
+                    &terms,
+                    string_style,
+                    highlight_style,
+                ),
+                _ => x,
+            }
+        };
+
+        // A row emitted as context for one match must not reappear when a
+        // later match's window overlaps it; tracking emitted indices in a set
+        // merges the windows, like grep's context merging.
+        let mut emitted: HashSet<usize> = HashSet::new();
+        let mut output: Vec<Value> = vec![];
+        for (idx, value) in values.iter().enumerate() {
+            if !value_should_be_printed(
+                value,
+                &filter_config,
+                &lower_terms,
+                &filter_terms,
+                span,
+                &cols_to_search_in_filter,
+                invert,
+                whole_record,
+                parse_json,
+                max_depth,
+            ) {
+                continue;
+            }
+            for context_idx in idx.saturating_sub(before)..=idx {
+                if !emitted.insert(context_idx) {
+                    continue;
+                }
+                let row = highlight(values[context_idx].clone());
+                if as_table {
+                    // Matches are emitted at their own index before they can
+                    // show up in a later window, so a context row here is
+                    // never itself a match.
+                    output.push(Value::record(
+                        record! {
+                            "row_number" => Value::int(context_idx as i64, span),
+                            "match" => Value::bool(context_idx == idx, span),
+                            "row" => row,
+                        },
+                        span,
+                    ));
+                } else {
+                    output.push(row);
+                }
+            }
+        }
+        return Ok(output.into_pipeline_data(ctrlc).set_metadata(metadata));
+    }
+
     match input {
         PipelineData::Empty => Ok(PipelineData::Empty),
         PipelineData::Value(_, _) => input
@@ -394,9 +975,13 @@ fn find_with_rest_and_highlight(
                         value,
                         &filter_config,
                         &lower_terms,
+                        &filter_terms,
                         span,
                         &cols_to_search_in_filter,
                         invert,
+                        whole_record,
+                        parse_json,
+                        max_depth,
                     )
                 },
                 ctrlc,
@@ -423,9 +1008,13 @@ fn find_with_rest_and_highlight(
                         value,
                         &filter_config,
                         &lower_terms,
+                        &filter_terms,
                         span,
                         &cols_to_search_in_filter,
                         invert,
+                        whole_record,
+                        parse_json,
+                        max_depth,
                     )
                 }),
             ctrlc.clone(),
@@ -438,6 +1027,7 @@ fn find_with_rest_and_highlight(
             ..
         } => {
             let mut output: Vec<Value> = vec![];
+            let mut line_number = 0;
             for filter_val in stream {
                 match filter_val {
                     Ok(value) => {
@@ -447,13 +1037,14 @@ fn find_with_rest_and_highlight(
                                 let split_char = if val.contains("\r\n") { "\r\n" } else { "\n" };
 
                                 for line in val.split(split_char) {
+                                    line_number += 1;
                                     for term in lower_terms.iter() {
                                         let term_str = term.into_string("", &filter_config);
                                         let lower_val = line.to_lowercase();
                                         if lower_val
                                             .contains(&term.into_string("", &config).to_lowercase())
                                         {
-                                            output.push(Value::string(
+                                            let text = Value::string(
                                                 highlight_search_string(
                                                     line,
                                                     &term_str,
@@ -461,7 +1052,18 @@ fn find_with_rest_and_highlight(
                                                     &highlight_style,
                                                 )?,
                                                 span,
-                                            ))
+                                            );
+                                            if as_table {
+                                                output.push(Value::record(
+                                                    record! {
+                                                        "line_number" => Value::int(line_number, span),
+                                                        "text" => text,
+                                                    },
+                                                    span,
+                                                ))
+                                            } else {
+                                                output.push(text)
+                                            }
                                         }
                                     }
                                 }
@@ -488,46 +1090,78 @@ fn find_with_rest_and_highlight(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn value_should_be_printed(
     value: &Value,
     filter_config: &Config,
     lower_terms: &[Value],
+    terms: &[Value],
     span: Span,
     columns_to_search: &[String],
     invert: bool,
+    whole_record: bool,
+    parse_json: bool,
+    max_depth: Option<i64>,
 ) -> bool {
     let lower_value = Value::string(value.into_string("", filter_config).to_lowercase(), span);
 
-    let mut match_found = lower_terms.iter().any(|term| match value {
-        Value::Bool { .. }
-        | Value::Int { .. }
-        | Value::Filesize { .. }
-        | Value::Duration { .. }
-        | Value::Date { .. }
-        | Value::Range { .. }
-        | Value::Float { .. }
-        | Value::Block { .. }
-        | Value::Closure { .. }
-        | Value::Nothing { .. }
-        | Value::Error { .. } => term_equals_value(term, &lower_value, span),
-        Value::String { .. }
-        | Value::List { .. }
-        | Value::CellPath { .. }
-        | Value::CustomValue { .. } => term_contains_value(term, &lower_value, span),
-        Value::Record { val, .. } => {
-            record_matches_term(val, columns_to_search, filter_config, term, span)
-        }
-        Value::LazyRecord { val, .. } => match val.collect() {
-            Ok(val) => match val {
-                Value::Record { val, .. } => {
-                    record_matches_term(&val, columns_to_search, filter_config, term, span)
+    let mut match_found = lower_terms.iter().zip(terms).any(|(lower_term, term)| {
+        match value {
+            Value::Bool { .. }
+            | Value::Int { .. }
+            | Value::Filesize { .. }
+            | Value::Duration { .. }
+            | Value::Date { .. }
+            | Value::Range { .. }
+            | Value::Float { .. }
+            | Value::Block { .. }
+            | Value::Closure { .. }
+            | Value::Nothing { .. }
+            | Value::Error { .. } => {
+                // A typed term (e.g. `find true` or `find 3kb`) is compared
+                // structurally to a cell of the same type; other terms fall back
+                // to the rendered-string comparison.
+                if term.get_type() == value.get_type() {
+                    term_equals_value(term, value, span)
+                } else {
+                    term_equals_value(lower_term, &lower_value, span)
                 }
-                _ => false,
+            }
+            Value::String { .. }
+            | Value::List { .. }
+            | Value::CellPath { .. }
+            | Value::CustomValue { .. } => term_contains_value(lower_term, &lower_value, span),
+            Value::Record { val, .. } => record_matches_term(
+                val,
+                columns_to_search,
+                filter_config,
+                lower_term,
+                span,
+                whole_record,
+                parse_json,
+                0,
+                max_depth,
+            ),
+            Value::LazyRecord { val, .. } => match val.collect() {
+                Ok(val) => match val {
+                    Value::Record { val, .. } => record_matches_term(
+                        &val,
+                        columns_to_search,
+                        filter_config,
+                        lower_term,
+                        span,
+                        whole_record,
+                        parse_json,
+                        0,
+                        max_depth,
+                    ),
+                    _ => false,
+                },
+                Err(_) => false,
             },
-            Err(_) => false,
-        },
-        Value::Binary { .. } => false,
-        Value::MatchPattern { .. } => false,
+            Value::Binary { .. } => false,
+            Value::MatchPattern { .. } => false,
+        }
     });
     if invert {
         match_found = !match_found;
@@ -545,22 +1179,75 @@ fn term_equals_value(term: &Value, value: &Value, span: Span) -> bool {
         .map_or(false, |value| value.is_true())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn record_matches_term(
     record: &Record,
     columns_to_search: &[String],
     filter_config: &Config,
     term: &Value,
     span: Span,
+    whole_record: bool,
+    parse_json: bool,
+    depth: i64,
+    max_depth: Option<i64>,
 ) -> bool {
     let cols_to_search = if columns_to_search.is_empty() {
         &record.cols
     } else {
         columns_to_search
     };
+
+    // With --whole-record, terms are matched against the searched cells
+    // rendered as a single string, so a phrase can span adjacent cells.
+    if whole_record {
+        let rendered = record
+            .iter()
+            .filter(|(col, _)| cols_to_search.contains(col))
+            .map(|(_, val)| val.into_string("", filter_config).to_lowercase())
+            .collect::<Vec<String>>()
+            .join(" ");
+        return term_contains_value(term, &Value::string(rendered, span), span);
+    }
+
     record.iter().any(|(col, val)| {
         if !cols_to_search.contains(col) {
             return false;
         }
+        // With --max-depth, nested cells past the limit are skipped outright,
+        // which bounds the cost of scanning deeply nested data. Depth 0 keeps
+        // top-level scalar cells only.
+        if let Some(max_depth) = max_depth {
+            if depth >= max_depth && matches!(val, Value::Record { .. } | Value::List { .. }) {
+                return false;
+            }
+        }
+        // With --parse-json, a string cell that parses as JSON is matched as
+        // the structured value instead of opaque text. The parsed structure
+        // lives one level down, so it also respects --max-depth.
+        if parse_json && max_depth.map_or(true, |max| depth < max) {
+            if let Value::String { val: cell, .. } = val {
+                if let Some(parsed) = parse_json_cell(cell, span) {
+                    if let Value::Record { val: inner, .. } = &parsed {
+                        return record_matches_term(
+                            inner,
+                            &[],
+                            filter_config,
+                            term,
+                            span,
+                            whole_record,
+                            parse_json,
+                            depth + 1,
+                            max_depth,
+                        );
+                    }
+                    let lower_parsed = Value::string(
+                        parsed.into_string("", filter_config).to_lowercase(),
+                        span,
+                    );
+                    return term_contains_value(term, &lower_parsed, span);
+                }
+            }
+        }
         let lower_val = if !val.is_error() {
             Value::string(
                 val.into_string("", filter_config).to_lowercase(),
@@ -585,6 +1272,34 @@ mod tests {
     }
 }
 
+/// Attempt to parse a cell's contents as a JSON object or array; anything else
+/// (including JSON scalars) is left to the plain-text matching path.
+fn parse_json_cell(contents: &str, span: Span) -> Option<Value> {
+    let trimmed = contents.trim_start();
+    if !(trimmed.starts_with('{') || trimmed.starts_with('[')) {
+        return None;
+    }
+    convert_string_to_value(contents.to_string(), span).ok()
+}
+
+/// Buffer the input into a single string value (`--slurp`), so matching sees
+/// the whole output instead of individual lines. Pretty-printed JSON from an
+/// external then matches across line boundaries, and `--parse-json` can parse
+/// it as one document. This holds the entire stream in memory, which is why
+/// line-by-line matching stays the default.
+fn slurp_input(input: PipelineData, head_span: Span) -> PipelineData {
+    match input {
+        PipelineData::ExternalStream { .. } => {
+            let span = input.span().unwrap_or(head_span);
+            let metadata = input.metadata();
+            let value = input.into_value(span);
+            PipelineData::Value(value, metadata)
+        }
+        // String values are already whole; just skip the line splitting.
+        input => input,
+    }
+}
+
 fn split_string_if_multiline(input: PipelineData, head_span: Span) -> PipelineData {
     let span = input.span().unwrap_or(head_span);
     match input {
@@ -605,3 +1320,377 @@ fn split_string_if_multiline(input: PipelineData, head_span: Span) -> PipelineDa
         _ => input,
     }
 }
+
+// --- `--query` support -------------------------------------------------------
+//
+// A minimal jq-like row query language, distinct from the term and regex
+// matching above:
+//
+//   query      = or_expr
+//   or_expr    = and_expr { "or" and_expr }
+//   and_expr   = comparison { "and" comparison }
+//   comparison = path [ op literal ]          (a bare path tests existence)
+//   op         = "==" | "!=" | ">=" | "<=" | ">" | "<"
+//   path       = "." name { "." name | "[]" }
+//   literal    = "..." | '...' | number | true | false | null
+//
+// `[]` fans out over list elements, so `.items[].status == "error"` is truthy
+// when any element matches. Comparing values of mismatched types is false for
+// every operator except `!=`.
+
+enum QueryExpr {
+    Or(Box<QueryExpr>, Box<QueryExpr>),
+    And(Box<QueryExpr>, Box<QueryExpr>),
+    Compare(QueryPath, CompareOp, QueryLit),
+    Exists(QueryPath),
+}
+
+enum PathSeg {
+    Field(String),
+    Spread,
+}
+
+struct QueryPath {
+    segs: Vec<PathSeg>,
+}
+
+enum CompareOp {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+enum QueryLit {
+    String(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Null,
+}
+
+/// Keep rows where, for every `{column: referenced column}` pair, the row's
+/// two cells compare equal. Rows missing either column never match; `--invert`
+/// keeps the rows that don't match instead.
+fn find_with_col_ref(
+    col_ref: Value,
+    engine_state: &EngineState,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let invert = call.has_flag("invert");
+    let ctrlc = engine_state.ctrlc.clone();
+    let head = call.head;
+
+    let pairs: Vec<(String, String)> = match col_ref {
+        Value::Record { val, .. } => val
+            .iter()
+            .map(|(col, referenced)| Ok((col.clone(), referenced.as_string()?)))
+            .collect::<Result<_, ShellError>>()?,
+        other => {
+            return Err(ShellError::UnsupportedInput(
+                "--col-ref expects a record of column-name pairs".to_string(),
+                "value originates from here".into(),
+                head,
+                other.span(),
+            ))
+        }
+    };
+
+    input.filter(
+        move |value| {
+            let matches = match value {
+                Value::Record { val: record, .. } => {
+                    pairs.iter().all(|(col, referenced)| {
+                        match (record.get(col), record.get(referenced)) {
+                            (Some(lhs), Some(rhs)) => lhs == rhs,
+                            _ => false,
+                        }
+                    })
+                }
+                _ => false,
+            };
+            matches != invert
+        },
+        ctrlc,
+    )
+}
+
+fn find_with_query(
+    query: Spanned<String>,
+    engine_state: &EngineState,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let invert = call.has_flag("invert");
+    let ctrlc = engine_state.ctrlc.clone();
+    let expr = QueryParser::new(&query.item, query_arg_span(engine_state, &query)).parse()?;
+
+    input.filter(move |value| eval_query(&expr, value) != invert, ctrlc)
+}
+
+/// Narrow the flag argument's span down to the query text itself (skipping the
+/// surrounding quotes) so parse errors can point into the query string.
+fn query_arg_span(engine_state: &EngineState, query: &Spanned<String>) -> Span {
+    let raw = engine_state.get_span_contents(query.span);
+    match String::from_utf8_lossy(raw).find(query.item.as_str()) {
+        Some(idx) if !query.item.is_empty() => Span::new(
+            query.span.start + idx,
+            query.span.start + idx + query.item.len(),
+        ),
+        _ => query.span,
+    }
+}
+
+struct QueryParser<'a> {
+    src: &'a str,
+    pos: usize,
+    base: Span,
+}
+
+impl<'a> QueryParser<'a> {
+    fn new(src: &'a str, base: Span) -> Self {
+        QueryParser { src, pos: 0, base }
+    }
+
+    fn parse(mut self) -> Result<QueryExpr, ShellError> {
+        let expr = self.parse_or()?;
+        self.skip_ws();
+        if self.pos < self.src.len() {
+            return Err(self.error("unexpected trailing input", self.pos, self.src.len()));
+        }
+        Ok(expr)
+    }
+
+    fn parse_or(&mut self) -> Result<QueryExpr, ShellError> {
+        let mut expr = self.parse_and()?;
+        loop {
+            self.skip_ws();
+            if !self.eat_keyword("or") {
+                return Ok(expr);
+            }
+            expr = QueryExpr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+    }
+
+    fn parse_and(&mut self) -> Result<QueryExpr, ShellError> {
+        let mut expr = self.parse_comparison()?;
+        loop {
+            self.skip_ws();
+            if !self.eat_keyword("and") {
+                return Ok(expr);
+            }
+            expr = QueryExpr::And(Box::new(expr), Box::new(self.parse_comparison()?));
+        }
+    }
+
+    fn parse_comparison(&mut self) -> Result<QueryExpr, ShellError> {
+        let path = self.parse_path()?;
+        self.skip_ws();
+        let op = if self.eat("==") {
+            CompareOp::Eq
+        } else if self.eat("!=") {
+            CompareOp::Ne
+        } else if self.eat(">=") {
+            CompareOp::Ge
+        } else if self.eat("<=") {
+            CompareOp::Le
+        } else if self.eat(">") {
+            CompareOp::Gt
+        } else if self.eat("<") {
+            CompareOp::Lt
+        } else {
+            return Ok(QueryExpr::Exists(path));
+        };
+        Ok(QueryExpr::Compare(path, op, self.parse_literal()?))
+    }
+
+    fn parse_path(&mut self) -> Result<QueryPath, ShellError> {
+        self.skip_ws();
+        if !self.eat(".") {
+            return Err(self.error("expected a path starting with `.`", self.pos, self.pos + 1));
+        }
+        let mut segs = vec![PathSeg::Field(self.parse_field_name()?)];
+        loop {
+            if self.eat("[]") {
+                segs.push(PathSeg::Spread);
+            } else if self.eat(".") {
+                segs.push(PathSeg::Field(self.parse_field_name()?));
+            } else {
+                return Ok(QueryPath { segs });
+            }
+        }
+    }
+
+    fn parse_field_name(&mut self) -> Result<String, ShellError> {
+        let name: String = self
+            .rest()
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || matches!(c, '_' | '-'))
+            .collect();
+        if name.is_empty() {
+            return Err(self.error("expected a field name", self.pos, self.pos + 1));
+        }
+        self.pos += name.len();
+        Ok(name)
+    }
+
+    fn parse_literal(&mut self) -> Result<QueryLit, ShellError> {
+        self.skip_ws();
+        let start = self.pos;
+        if let Some(quote @ ('"' | '\'')) = self.rest().chars().next() {
+            self.pos += 1;
+            return match self.rest().find(quote) {
+                Some(end) => {
+                    let string = self.rest()[..end].to_string();
+                    self.pos += end + 1;
+                    Ok(QueryLit::String(string))
+                }
+                None => Err(self.error("unterminated string literal", start, self.src.len())),
+            };
+        }
+        if self.eat_keyword("true") {
+            Ok(QueryLit::Bool(true))
+        } else if self.eat_keyword("false") {
+            Ok(QueryLit::Bool(false))
+        } else if self.eat_keyword("null") {
+            Ok(QueryLit::Null)
+        } else {
+            let num: String = self
+                .rest()
+                .chars()
+                .take_while(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+                .collect();
+            if num.is_empty() {
+                return Err(self.error(
+                    "expected a literal (string, number, true, false or null)",
+                    start,
+                    start + 1,
+                ));
+            }
+            self.pos += num.len();
+            if let Ok(int) = num.parse::<i64>() {
+                Ok(QueryLit::Int(int))
+            } else if let Ok(float) = num.parse::<f64>() {
+                Ok(QueryLit::Float(float))
+            } else {
+                Err(self.error("invalid number literal", start, self.pos))
+            }
+        }
+    }
+
+    fn rest(&self) -> &str {
+        &self.src[self.pos..]
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let followed_by_word = self
+            .rest()
+            .strip_prefix(keyword)
+            .and_then(|rest| rest.chars().next())
+            .is_some_and(|c| c.is_alphanumeric() || c == '_');
+        if !followed_by_word && self.rest().starts_with(keyword) {
+            self.pos += keyword.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_ws(&mut self) {
+        while let Some(c) = self.rest().chars().next() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn error(&self, msg: &str, from: usize, to: usize) -> ShellError {
+        // `base` covers the query text itself, so offsets index straight into it
+        let span = Span::new(
+            (self.base.start + from).min(self.base.end),
+            (self.base.start + to).min(self.base.end),
+        );
+        ShellError::GenericError(
+            "Invalid find query".into(),
+            msg.into(),
+            Some(span),
+            Some("queries combine paths, comparisons and and/or, e.g. `.items[].status == \"error\"`".into()),
+            vec![],
+        )
+    }
+}
+
+fn eval_query(expr: &QueryExpr, row: &Value) -> bool {
+    match expr {
+        QueryExpr::Or(lhs, rhs) => eval_query(lhs, row) || eval_query(rhs, row),
+        QueryExpr::And(lhs, rhs) => eval_query(lhs, row) && eval_query(rhs, row),
+        QueryExpr::Exists(path) => !resolve_query_path(path, row).is_empty(),
+        QueryExpr::Compare(path, op, lit) => resolve_query_path(path, row)
+            .iter()
+            .any(|value| compare_query(value, op, lit)),
+    }
+}
+
+/// Walk a path into a row, fanning out over list elements at every `[]`.
+/// Missing fields simply drop out rather than erroring.
+fn resolve_query_path<'a>(path: &QueryPath, row: &'a Value) -> Vec<&'a Value> {
+    let mut current = vec![row];
+    for seg in &path.segs {
+        let mut next = vec![];
+        for value in current {
+            match (seg, value) {
+                (PathSeg::Field(name), Value::Record { val, .. }) => {
+                    if let Some(found) = val
+                        .cols
+                        .iter()
+                        .zip(val.vals.iter())
+                        .find_map(|(col, val)| (col == name).then_some(val))
+                    {
+                        next.push(found);
+                    }
+                }
+                (PathSeg::Spread, Value::List { vals, .. }) => next.extend(vals.iter()),
+                _ => {}
+            }
+        }
+        current = next;
+    }
+    current
+}
+
+fn compare_query(value: &Value, op: &CompareOp, lit: &QueryLit) -> bool {
+    use std::cmp::Ordering;
+
+    let ordering = match (value, lit) {
+        (Value::String { val, .. }, QueryLit::String(rhs)) => val.as_str().partial_cmp(rhs.as_str()),
+        (Value::Int { val, .. }, QueryLit::Int(rhs)) => val.partial_cmp(rhs),
+        (Value::Int { val, .. }, QueryLit::Float(rhs)) => (*val as f64).partial_cmp(rhs),
+        (Value::Float { val, .. }, QueryLit::Int(rhs)) => val.partial_cmp(&(*rhs as f64)),
+        (Value::Float { val, .. }, QueryLit::Float(rhs)) => val.partial_cmp(rhs),
+        (Value::Bool { val, .. }, QueryLit::Bool(rhs)) => val.partial_cmp(rhs),
+        (Value::Nothing { .. }, QueryLit::Null) => Some(Ordering::Equal),
+        _ => None,
+    };
+
+    match op {
+        CompareOp::Eq => ordering == Some(Ordering::Equal),
+        CompareOp::Ne => ordering != Some(Ordering::Equal),
+        CompareOp::Gt => ordering == Some(Ordering::Greater),
+        CompareOp::Ge => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+        CompareOp::Lt => ordering == Some(Ordering::Less),
+        CompareOp::Le => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+    }
+}
@@ -1,11 +1,19 @@
-use nu_engine::CallExt;
+use crate::formats::value_to_string;
+use nu_engine::{eval_block, CallExt};
 use nu_protocol::ast::{Call, CellPath, PathMember};
-use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
 use nu_protocol::{
     record, Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData,
-    PipelineIterator, Record, ShellError, Signature, Span, SyntaxShape, Type, Value,
+    PipelineIterator, Range, Record, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashSet};
+
+/// A single output column: either an ordinary cell path into the row, or a
+/// closure evaluated with the row as input to compute the cell value.
+enum Projection {
+    Path(CellPath),
+    Computed { name: String, closure: Closure },
+}
 
 #[derive(Clone)]
 pub struct Select;
@@ -28,11 +36,56 @@ impl Command for Select {
                 "ignore missing data (make all cell path members optional)",
                 Some('i'),
             )
+            .switch(
+                "ignore-case",
+                "match column names case-insensitively, keeping the actual column's casing",
+                None,
+            )
+            .switch(
+                "unique",
+                "remove duplicate rows from the output, keeping first-seen order",
+                Some('u'),
+            )
+            .switch(
+                "compact",
+                "omit rows whose selected cells are all null after projection",
+                Some('c'),
+            )
+            .switch(
+                "values",
+                "with exactly one selected column, output the bare values instead of single-column records",
+                Some('v'),
+            )
+            .named(
+                "default",
+                SyntaxShape::Any,
+                "value to fill missing cells with instead of erroring",
+                Some('d'),
+            )
+            .named(
+                "as",
+                SyntaxShape::String,
+                "name for the first computed (closure) column",
+                None,
+            )
+            .named(
+                "rename",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::String])),
+                "closure applied to each output column name to compute the new name",
+                None,
+            )
+            .named(
+                "depth",
+                SyntaxShape::Int,
+                "prune selected cell values to this many levels of nesting (0 keeps only scalars)",
+                None,
+            )
             .rest(
                 "rest",
                 SyntaxShape::OneOf(vec![
                     SyntaxShape::CellPath,
                     SyntaxShape::List(Box::new(SyntaxShape::CellPath)),
+                    SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
                 ]),
                 "the columns to select from the table",
             )
@@ -47,7 +100,32 @@ impl Command for Select {
     fn extra_usage(&self) -> &str {
         r#"This differs from `get` in that, rather than accessing the given value in the data structure,
 it removes all non-selected values from the structure. Hence, using `select` on a table will
-produce a table, a list will produce a list, and a record will produce a record."#
+produce a table, a list will produce a list, and a record will produce a record.
+
+With `--unique`, duplicates are judged by the projected columns only, and the set of seen
+rows is kept in memory for the duration of the command.
+
+With `--default`, cells missing from a row are filled with the given value instead of
+erroring; it takes precedence over `--ignore-errors`, which would fill them with null.
+
+With `--values`, the single selected column is unwrapped to a flat list of its values,
+which bridges `select` and `get`: the output matches `get name`, but missing cells still
+honor `--ignore-errors` and `--default`. Selecting more than one column with `--values`
+is an error.
+
+With `--compact`, rows whose selected cells are all null after projection are omitted;
+a row with at least one non-null cell is kept. This pairs with `--ignore-errors`, which
+fills missing cells with null. A record input is a single value, not a row, and is
+never dropped.
+
+With `--rename`, the closure receives each output column's derived name (as input and as its
+first argument) and must return the new name; it composes with any way the columns were
+chosen. Transformed names that collide are an error.
+
+Columns prefixed with `^` are rejected instead of selected: the output then starts from all
+of the input's columns in input order, drops each rejected name, and keeps any column that
+is also selected explicitly (explicit selection wins over rejection). Remaining arguments
+keep their usual meaning and are appended when not already present."#
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -62,18 +140,59 @@ produce a table, a list will produce a list, and a record will produce a record.
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let columns: Vec<Value> = call.rest(engine_state, stack, 0)?;
-        let mut new_columns: Vec<CellPath> = vec![];
+        let as_name: Option<String> = call.get_flag(engine_state, stack, "as")?;
+
+        // Open-ended row ranges are clamped to the input length, so streams
+        // have to be collected up front to know it.
+        let input = if columns
+            .iter()
+            .any(|v| matches!(v, Value::Range { val, .. } if val.is_unbounded()))
+        {
+            let metadata = input.metadata();
+            PipelineData::Value(input.into_value(call.head), metadata)
+        } else {
+            input
+        };
+        let row_count = match &input {
+            PipelineData::Value(Value::List { vals, .. }, ..) => Some(vals.len()),
+            PipelineData::Value(..) => Some(1),
+            _ => None,
+        };
+
+        let mut computed_count = 0;
+        let mut new_columns: Vec<Projection> = vec![];
+        let mut rejected_columns: Vec<String> = vec![];
         for col_val in columns {
             let col_span = &col_val.span();
             match col_val {
                 Value::CellPath { val, .. } => {
-                    new_columns.push(val);
+                    // `^name` rejects the column instead of selecting it
+                    if let [PathMember::String { val: name, .. }] = &val.members[..] {
+                        if let Some(rejected) = name.strip_prefix('^') {
+                            rejected_columns.push(rejected.to_string());
+                            continue;
+                        }
+                    }
+                    new_columns.push(Projection::Path(val));
+                }
+                Value::Closure { val, .. } => {
+                    // The first closure takes the `--as` name; later ones are numbered.
+                    let name = match (&as_name, computed_count) {
+                        (Some(name), 0) => name.clone(),
+                        _ => format!("computed_{computed_count}"),
+                    };
+                    computed_count += 1;
+                    new_columns.push(Projection::Computed { name, closure: val });
                 }
                 Value::List { vals, .. } => {
                     for value in vals {
                         let val_span = &value.span();
                         match value {
                             Value::String { val, .. } => {
+                                if let Some(rejected) = val.strip_prefix('^') {
+                                    rejected_columns.push(rejected.to_string());
+                                    continue;
+                                }
                                 let cv = CellPath {
                                     members: vec![PathMember::String {
                                         val: val.clone(),
@@ -81,7 +200,7 @@ produce a table, a list will produce a list, and a record will produce a record.
                                         optional: false,
                                     }],
                                 };
-                                new_columns.push(cv.clone());
+                                new_columns.push(Projection::Path(cv.clone()));
                             }
                             Value::Int { val, .. } => {
                                 let cv = CellPath {
@@ -91,20 +210,26 @@ produce a table, a list will produce a list, and a record will produce a record.
                                         optional: false,
                                     }],
                                 };
-                                new_columns.push(cv.clone());
+                                new_columns.push(Projection::Path(cv.clone()));
                             }
                             y => {
                                 return Err(ShellError::CantConvert {
                                     to_type: "cell path".into(),
                                     from_type: y.get_type().to_string(),
                                     span: y.span(),
-                                    help: None,
+                                    help: Some(
+                                        "select columns must be strings or integers".into(),
+                                    ),
                                 });
                             }
                         }
                     }
                 }
                 Value::String { val, .. } => {
+                    if let Some(rejected) = val.strip_prefix('^') {
+                        rejected_columns.push(rejected.to_string());
+                        continue;
+                    }
                     let cv = CellPath {
                         members: vec![PathMember::String {
                             val: val.clone(),
@@ -112,7 +237,7 @@ produce a table, a list will produce a list, and a record will produce a record.
                             optional: false,
                         }],
                     };
-                    new_columns.push(cv.clone());
+                    new_columns.push(Projection::Path(cv.clone()));
                 }
                 Value::Int { val, .. } => {
                     let cv = CellPath {
@@ -122,28 +247,116 @@ produce a table, a list will produce a list, and a record will produce a record.
                             optional: false,
                         }],
                     };
-                    new_columns.push(cv.clone());
+                    new_columns.push(Projection::Path(cv.clone()));
+                }
+                Value::Range { val, .. } => {
+                    for row in expand_row_range(&val, *col_span, row_count)? {
+                        let cv = CellPath {
+                            members: vec![PathMember::Int {
+                                val: row,
+                                span: *col_span,
+                                optional: false,
+                            }],
+                        };
+                        new_columns.push(Projection::Path(cv));
+                    }
                 }
                 x => {
                     return Err(ShellError::CantConvert {
                         to_type: "cell path".into(),
                         from_type: x.get_type().to_string(),
                         span: x.span(),
-                        help: None,
+                        help: Some("select columns must be strings or integers".into()),
                     });
                 }
             }
         }
+        // `^name` rejections start from the input's own column set, which
+        // requires collecting the input to inspect its first row.
+        let (input, mut new_columns) = if rejected_columns.is_empty() {
+            (input, new_columns)
+        } else {
+            let metadata = input.metadata();
+            let value = input.into_value(call.head);
+            let input_columns = match &value {
+                Value::List { vals, .. } => match vals.first() {
+                    Some(Value::Record { val, .. }) => val.cols.clone(),
+                    _ => vec![],
+                },
+                Value::Record { val, .. } => val.cols.clone(),
+                _ => vec![],
+            };
+            let expanded =
+                expand_rejections(new_columns, &rejected_columns, &input_columns, call.head);
+            (PipelineData::Value(value, metadata), expanded)
+        };
+
+        // Case-insensitive selection resolves the requested names to the
+        // input's actual column casing up front, so the output preserves it
+        // and collisions are caught; deeper path members rely on
+        // `follow_cell_path`'s own insensitive mode.
+        let insensitive = call.has_flag("ignore-case");
+        let (input, mut new_columns) = if insensitive {
+            let metadata = input.metadata();
+            let value = input.into_value(call.head);
+            let input_columns = match &value {
+                Value::List { vals, .. } => match vals.first() {
+                    Some(Value::Record { val, .. }) => val.cols.clone(),
+                    _ => vec![],
+                },
+                Value::Record { val, .. } => val.cols.clone(),
+                _ => vec![],
+            };
+            resolve_columns_insensitively(&mut new_columns, &input_columns)?;
+            (PipelineData::Value(value, metadata), new_columns)
+        } else {
+            (input, new_columns)
+        };
+
         let ignore_errors = call.has_flag("ignore-errors");
+        let default: Option<Value> = call.get_flag(engine_state, stack, "default")?;
         let span = call.head;
 
-        if ignore_errors {
-            for cell_path in &mut new_columns {
-                cell_path.make_optional();
+        if ignore_errors && default.is_none() {
+            for projection in &mut new_columns {
+                if let Projection::Path(cell_path) = projection {
+                    cell_path.make_optional();
+                }
+            }
+        }
+
+        let unique = call.has_flag("unique");
+        let values = call.has_flag("values");
+        let compact = call.has_flag("compact");
+        let rename: Option<Closure> = call.get_flag(engine_state, stack, "rename")?;
+
+        let depth: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "depth")?;
+        if let Some(depth) = &depth {
+            if depth.item < 0 {
+                return Err(ShellError::IncorrectValue {
+                    msg: "depth cannot be negative".into(),
+                    val_span: depth.span,
+                    call_span: call.head,
+                });
             }
         }
+        let depth = depth.map(|depth| depth.item);
 
-        select(engine_state, span, new_columns, input)
+        select(
+            engine_state,
+            stack,
+            call,
+            span,
+            new_columns,
+            unique,
+            values,
+            compact,
+            rename,
+            default,
+            depth,
+            insensitive,
+            input,
+        )
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -184,43 +397,206 @@ produce a table, a list will produce a list, and a record will produce a record.
                 example: "let rows = [0 2];[[name type size]; [Cargo.toml toml 1kb] [Cargo.lock toml 2kb] [file.json json 3kb]] | select $rows",
                 result: None
             },
+            Example {
+                description: "Select a nested record but keep only one level of structure",
+                example: "{a: {b: {c: 1} d: 2} e: 3} | select a e --depth 1",
+                result: Some(Value::test_record(record! {
+                    "a" => Value::test_record(record! {
+                        "b" => Value::test_nothing(),
+                        "d" => Value::test_int(2),
+                    }),
+                    "e" => Value::test_int(3),
+                })),
+            },
+            Example {
+                description: "Select a column regardless of its casing",
+                example: "{Name: foo} | select name --ignore-case",
+                result: Some(Value::test_record(record! {
+                    "Name" => Value::test_string("foo"),
+                })),
+            },
+            Example {
+                description: "Select everything except one column",
+                example: r#"{a: 1 b: 2 c: 3} | select "^b""#,
+                result: Some(Value::test_record(record! {
+                    "a" => Value::test_int(1),
+                    "c" => Value::test_int(3),
+                })),
+            },
+            Example {
+                description: "Select a single column as a flat list of its values",
+                example: "[{name: a} {name: b}] | select name --values",
+                result: Some(Value::test_list(vec![
+                    Value::test_string("a"),
+                    Value::test_string("b"),
+                ])),
+            },
+            Example {
+                description: "Select columns and transform their names with a closure",
+                example: "[[a b]; [1 2]] | select a b --rename {|name| $name | str upcase }",
+                result: Some(Value::test_list(
+                    vec![Value::test_record(record! {
+                        "A" => Value::test_int(1),
+                        "B" => Value::test_int(2),
+                    })],
+                )),
+            },
+            Example {
+                description: "Select a column and drop rows that end up entirely null",
+                example: "[{a: 1} {b: 2}] | select a --ignore-errors --compact",
+                result: Some(Value::test_list(vec![Value::test_record(record! {
+                    "a" => Value::test_int(1),
+                })])),
+            },
+            Example {
+                description: "Select a column and drop duplicate rows",
+                example: "[{a: 1 b: x} {a: 1 b: y} {a: 2 b: z}] | select a --unique",
+                result: Some(Value::test_list(
+                    vec![
+                        Value::test_record(record! {
+                            "a" => Value::test_int(1)
+                        }),
+                        Value::test_record(record! {
+                            "a" => Value::test_int(2)
+                        }),
+                    ],
+                )),
+            },
+            Example {
+                description: "Select columns, filling missing cells with a default",
+                example: "[{a: 1} {a: 2 b: 3}] | select a b --default 0",
+                result: Some(Value::test_list(
+                    vec![
+                        Value::test_record(record! {
+                            "a" => Value::test_int(1),
+                            "b" => Value::test_int(0),
+                        }),
+                        Value::test_record(record! {
+                            "a" => Value::test_int(2),
+                            "b" => Value::test_int(3),
+                        }),
+                    ],
+                )),
+            },
+            Example {
+                description: "Select a column along with a computed column produced by a closure",
+                example: "[[first last]; [grace hopper]] | select first {|r| $r.first + ' ' + $r.last } --as full",
+                result: Some(Value::test_list(
+                    vec![Value::test_record(record! {
+                        "first" => Value::test_string("grace"),
+                        "full" => Value::test_string("grace hopper"),
+                    })],
+                )),
+            },
         ]
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn select(
     engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
     call_span: Span,
-    columns: Vec<CellPath>,
+    columns: Vec<Projection>,
+    unique: bool,
+    values: bool,
+    compact: bool,
+    rename: Option<Closure>,
+    default: Option<Value>,
+    depth: Option<i64>,
+    insensitive: bool,
     input: PipelineData,
 ) -> Result<PipelineData, ShellError> {
+    let mut seen_rows = HashSet::new();
     let mut unique_rows: BTreeSet<usize> = BTreeSet::new();
 
-    let mut new_columns = vec![];
+    let mut new_columns: Vec<Projection> = vec![];
 
     for column in columns {
-        let CellPath { ref members } = column;
-        match members.get(0) {
-            Some(PathMember::Int { val, span, .. }) => {
-                if members.len() > 1 {
-                    return Err(ShellError::GenericError(
-                        "Select only allows row numbers for rows".into(),
-                        "extra after row number".into(),
-                        Some(*span),
-                        None,
-                        Vec::new(),
-                    ));
-                }
-                unique_rows.insert(*val);
-            }
-            _ => {
-                if !new_columns.contains(&column) {
-                    new_columns.push(column)
-                }
+        match column {
+            Projection::Path(column) => {
+                let CellPath { ref members } = column;
+                match members.get(0) {
+                    Some(PathMember::Int { val, span, .. }) => {
+                        if members.len() > 1 {
+                            return Err(ShellError::GenericError(
+                                "Select only allows row numbers for rows".into(),
+                                "extra after row number".into(),
+                                Some(*span),
+                                None,
+                                Vec::new(),
+                            ));
+                        }
+                        unique_rows.insert(*val);
+                    }
+                    _ => {
+                        if !new_columns
+                            .iter()
+                            .any(|p| matches!(p, Projection::Path(existing) if existing == &column))
+                        {
+                            new_columns.push(Projection::Path(column))
+                        }
+                    }
+                };
             }
-        };
+            computed => new_columns.push(computed),
+        }
     }
     let columns = new_columns;
+    check_duplicate_output_columns(&columns, call_span)?;
+
+    // `--values` unwraps the single-column records to bare values, so it only
+    // makes sense for exactly one output column; row-number selections don't
+    // count, they just pick which rows flow through.
+    if values && columns.len() != 1 {
+        return Err(ShellError::GenericError(
+            "--values needs exactly one selected column".into(),
+            format!("{} columns selected", columns.len()),
+            Some(call_span),
+            Some("use `select name --values` to get the flat list of one column's values".into()),
+            Vec::new(),
+        ));
+    }
+
+    // `--rename` maps every output column name through the closure up front;
+    // collisions among the transformed names would build records with
+    // duplicate columns, so they are rejected here like the untransformed
+    // names were above.
+    let renamed: Option<Vec<String>> = match &rename {
+        Some(closure) => {
+            let mut new_names = Vec::with_capacity(columns.len());
+            for projection in &columns {
+                let name = match projection {
+                    Projection::Path(path) => output_column_name(path),
+                    Projection::Computed { name, .. } => name.clone(),
+                };
+                new_names.push(eval_renamed_column_name(
+                    engine_state,
+                    stack,
+                    call,
+                    closure,
+                    &name,
+                    call_span,
+                )?);
+            }
+            if let Some(duplicate) = new_names
+                .iter()
+                .enumerate()
+                .find_map(|(idx, name)| new_names[..idx].contains(name).then_some(name))
+            {
+                return Err(ShellError::GenericError(
+                    "--rename produced duplicate column names".into(),
+                    format!("two columns were renamed to '{duplicate}'"),
+                    Some(call_span),
+                    None,
+                    Vec::new(),
+                ));
+            }
+            Some(new_names)
+        }
+        None => None,
+    };
 
     let input = if !unique_rows.is_empty() {
         // let skip = call.has_flag("skip");
@@ -250,23 +626,58 @@ fn select(
                     for input_val in input_vals {
                         if !columns.is_empty() {
                             let mut record = Record::new();
-                            for path in &columns {
-                                //FIXME: improve implementation to not clone
-                                match input_val.clone().follow_cell_path(&path.members, false) {
-                                    Ok(fetcher) => {
-                                        record.push(path.into_string().replace('.', "_"), fetcher);
-                                        if !columns_with_value.contains(&path) {
-                                            columns_with_value.push(path);
+                            for projection in &columns {
+                                match projection {
+                                    Projection::Path(path) => {
+                                        //FIXME: improve implementation to not clone
+                                        match input_val
+                                            .clone()
+                                            .follow_cell_path(&path.members, insensitive)
+                                        {
+                                            Ok(fetcher) => {
+                                                record.push(
+                                                    output_column_name(path),
+                                                    prune_cell_depth(fetcher, depth),
+                                                );
+                                                if !columns_with_value.contains(&path) {
+                                                    columns_with_value.push(path);
+                                                }
+                                            }
+                                            Err(e) => match &default {
+                                                Some(default) => record.push(
+                                                    output_column_name(path),
+                                                    default.clone(),
+                                                ),
+                                                None => return Err(e),
+                                            },
                                         }
                                     }
-                                    Err(e) => {
-                                        return Err(e);
+                                    Projection::Computed { name, closure } => {
+                                        let cell = eval_computed_cell(
+                                            engine_state,
+                                            stack,
+                                            call,
+                                            closure,
+                                            &input_val,
+                                            call_span,
+                                        )?;
+                                        record.push(name.clone(), cell);
                                     }
                                 }
                             }
 
-                            output.push(Value::record(record, span))
-                        } else {
+                            if compact && row_is_all_null(&record) {
+                                continue;
+                            }
+                            let row = if values {
+                                unwrap_single_value(record, span)
+                            } else {
+                                Value::record(apply_renames(record, renamed.as_deref()), span)
+                            };
+                            if !unique || row_is_first_seen(&row, &mut seen_rows)? {
+                                output.push(row)
+                            }
+                        } else if !unique || row_is_first_seen(&input_val, &mut seen_rows)? {
                             output.push(input_val)
                         }
                     }
@@ -280,19 +691,47 @@ fn select(
                     if !columns.is_empty() {
                         let mut record = Record::new();
 
-                        for cell_path in columns {
-                            // FIXME: remove clone
-                            match v.clone().follow_cell_path(&cell_path.members, false) {
-                                Ok(result) => {
-                                    record.push(cell_path.into_string().replace('.', "_"), result);
+                        for projection in columns {
+                            match projection {
+                                Projection::Path(cell_path) => {
+                                    // FIXME: remove clone
+                                    match v.clone().follow_cell_path(&cell_path.members, insensitive)
+                                    {
+                                        Ok(result) => {
+                                            record.push(
+                                                output_column_name(&cell_path),
+                                                prune_cell_depth(result, depth),
+                                            );
+                                        }
+                                        Err(e) => match &default {
+                                            Some(default) => record.push(
+                                                output_column_name(&cell_path),
+                                                default.clone(),
+                                            ),
+                                            None => return Err(e),
+                                        },
+                                    }
+                                }
+                                Projection::Computed { name, closure } => {
+                                    let cell = eval_computed_cell(
+                                        engine_state,
+                                        stack,
+                                        call,
+                                        &closure,
+                                        &v,
+                                        call_span,
+                                    )?;
+                                    record.push(name, cell);
                                 }
-                                Err(e) => return Err(e),
                             }
                         }
 
-                        Ok(Value::record(record, call_span)
-                            .into_pipeline_data()
-                            .set_metadata(metadata))
+                        let output = if values {
+                            unwrap_single_value(record, call_span)
+                        } else {
+                            Value::record(apply_renames(record, renamed.as_deref()), call_span)
+                        };
+                        Ok(output.into_pipeline_data().set_metadata(metadata))
                     } else {
                         Ok(v.into_pipeline_data().set_metadata(metadata))
                     }
@@ -300,27 +739,59 @@ fn select(
             }
         }
         PipelineData::ListStream(stream, metadata, ..) => {
-            let mut values = vec![];
+            let mut output_values = vec![];
 
             for x in stream {
                 if !columns.is_empty() {
                     let mut record = Record::new();
-                    for path in &columns {
-                        //FIXME: improve implementation to not clone
-                        match x.clone().follow_cell_path(&path.members, false) {
-                            Ok(value) => {
-                                record.push(path.into_string().replace('.', "_"), value);
+                    for projection in &columns {
+                        match projection {
+                            Projection::Path(path) => {
+                                //FIXME: improve implementation to not clone
+                                match x.clone().follow_cell_path(&path.members, insensitive) {
+                                    Ok(value) => {
+                                        record.push(
+                                            output_column_name(path),
+                                            prune_cell_depth(value, depth),
+                                        );
+                                    }
+                                    Err(e) => match &default {
+                                        Some(default) => record
+                                            .push(output_column_name(path), default.clone()),
+                                        None => return Err(e),
+                                    },
+                                }
+                            }
+                            Projection::Computed { name, closure } => {
+                                let cell = eval_computed_cell(
+                                    engine_state,
+                                    stack,
+                                    call,
+                                    closure,
+                                    &x,
+                                    call_span,
+                                )?;
+                                record.push(name.clone(), cell);
                             }
-                            Err(e) => return Err(e),
                         }
                     }
-                    values.push(Value::record(record, call_span));
-                } else {
-                    values.push(x);
+                    if compact && row_is_all_null(&record) {
+                        continue;
+                    }
+                    let row = if values {
+                        unwrap_single_value(record, call_span)
+                    } else {
+                        Value::record(apply_renames(record, renamed.as_deref()), call_span)
+                    };
+                    if !unique || row_is_first_seen(&row, &mut seen_rows)? {
+                        output_values.push(row);
+                    }
+                } else if !unique || row_is_first_seen(&x, &mut seen_rows)? {
+                    output_values.push(x);
                 }
             }
 
-            Ok(values
+            Ok(output_values
                 .into_pipeline_data(engine_state.ctrlc.clone())
                 .set_metadata(metadata))
         }
@@ -328,6 +799,344 @@ fn select(
     }
 }
 
+/// Whether every projected cell in a row is `nothing` (`--compact`). A row
+/// with at least one non-null cell is kept; an empty projection never matches.
+fn row_is_all_null(record: &Record) -> bool {
+    !record.vals.is_empty()
+        && record
+            .vals
+            .iter()
+            .all(|val| matches!(val, Value::Nothing { .. }))
+}
+
+/// Swap a projected record's column names for the `--rename`d ones. The
+/// record is built with exactly one column per projection, in projection
+/// order, so the transformed names line up by position.
+fn apply_renames(mut record: Record, renamed: Option<&[String]>) -> Record {
+    if let Some(names) = renamed {
+        record.cols = names.to_vec();
+    }
+    record
+}
+
+/// Evaluates the `--rename` closure with an output column name both as the
+/// first positional argument and as pipeline input, expecting a string back.
+/// Failures are wrapped with the column name as context.
+fn eval_renamed_column_name(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    closure: &Closure,
+    name: &str,
+    span: Span,
+) -> Result<String, ShellError> {
+    let block = engine_state.get_block(closure.block_id);
+    let mut callee_stack = stack.captures_to_stack(&closure.captures);
+
+    if let Some(var) = block.signature.get_positional(0) {
+        if let Some(var_id) = &var.var_id {
+            callee_stack.add_var(*var_id, Value::string(name, span));
+        }
+    }
+
+    eval_block(
+        engine_state,
+        &mut callee_stack,
+        block,
+        Value::string(name, span).into_pipeline_data(),
+        call.redirect_stdout,
+        call.redirect_stderr,
+    )
+    .and_then(|data| data.into_value(span).as_string())
+    .map_err(|err| {
+        ShellError::GenericError(
+            "--rename closure failed".into(),
+            format!("while renaming column '{name}'"),
+            Some(span),
+            None,
+            vec![err],
+        )
+    })
+}
+
+/// Unwrap a projected single-column record to its bare value (`--values`). The
+/// column count is validated up front, so the record always has exactly one
+/// cell; the fallback only guards against an impossible empty record.
+fn unwrap_single_value(record: Record, span: Span) -> Value {
+    record
+        .vals
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| Value::nothing(span))
+}
+
+/// Expand a row-range argument (`select 0..3`) into individual row indices.
+/// Open-ended ranges are clamped to `row_count`, which the caller obtains by
+/// collecting the input up front.
+fn expand_row_range(
+    range: &Range,
+    span: Span,
+    row_count: Option<usize>,
+) -> Result<Vec<usize>, ShellError> {
+    let reversed_err = || {
+        ShellError::GenericError(
+            "Select only allows ascending row ranges".into(),
+            "range counts down".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    };
+    let non_integer_err = || {
+        ShellError::GenericError(
+            "Select only allows integer row ranges".into(),
+            "range bound is not an integer".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    };
+
+    let from = range.from().map_err(|_| non_integer_err())?;
+    let step = match &range.incr {
+        Value::Int { val, .. } => *val,
+        _ => return Err(non_integer_err()),
+    };
+    if step < 0 {
+        return Err(reversed_err());
+    }
+    if from < 0 {
+        return Err(ShellError::GenericError(
+            "Select only allows non-negative row numbers".into(),
+            "range starts below row 0".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        ));
+    }
+
+    let to = if range.is_unbounded() {
+        match row_count {
+            Some(rows) => rows.saturating_sub(1) as i64,
+            None => return Ok(vec![]),
+        }
+    } else {
+        range.to().map_err(|_| non_integer_err())?
+    };
+    if to < from {
+        return Err(reversed_err());
+    }
+
+    Ok((from..=to)
+        .step_by(step as usize)
+        .map(|row| row as usize)
+        .collect())
+}
+
+/// Prune a selected cell to `depth` levels of nesting when `--depth` is given.
+/// Records and lists past the limit are replaced with `nothing`, so a depth of
+/// 0 keeps only scalar cells.
+fn prune_cell_depth(value: Value, depth: Option<i64>) -> Value {
+    match depth {
+        Some(depth) => truncate_depth(value, depth),
+        None => value,
+    }
+}
+
+fn truncate_depth(value: Value, depth: i64) -> Value {
+    let span = value.span();
+    match value {
+        Value::Record { val, .. } => {
+            if depth <= 0 {
+                Value::nothing(span)
+            } else {
+                let vals = val
+                    .vals
+                    .into_iter()
+                    .map(|val| truncate_depth(val, depth - 1))
+                    .collect();
+                Value::record(Record::from_raw_cols_vals(val.cols, vals), span)
+            }
+        }
+        Value::List { vals, .. } => {
+            if depth <= 0 {
+                Value::nothing(span)
+            } else {
+                Value::list(
+                    vals.into_iter()
+                        .map(|val| truncate_depth(val, depth - 1))
+                        .collect(),
+                    span,
+                )
+            }
+        }
+        value => value,
+    }
+}
+
+/// Expand `^name` rejections into an explicit projection list. The output
+/// starts from the input's columns in input order, skipping rejected names; a
+/// column that is both rejected and explicitly selected is kept (explicit
+/// selection wins). Explicit selections missing from the input, row numbers,
+/// nested paths, and computed columns are appended after, in argument order.
+fn expand_rejections(
+    columns: Vec<Projection>,
+    rejected: &[String],
+    input_columns: &[String],
+    span: Span,
+) -> Vec<Projection> {
+    let included: HashSet<&str> = columns.iter().filter_map(single_column_name).collect();
+
+    let mut expanded: Vec<Projection> = input_columns
+        .iter()
+        .filter(|col| !rejected.contains(col) || included.contains(col.as_str()))
+        .map(|col| {
+            Projection::Path(CellPath {
+                members: vec![PathMember::String {
+                    val: col.clone(),
+                    span,
+                    optional: false,
+                }],
+            })
+        })
+        .collect();
+
+    for projection in columns {
+        match single_column_name(&projection) {
+            Some(name) if input_columns.iter().any(|col| col == name) => {}
+            _ => expanded.push(projection),
+        }
+    }
+
+    expanded
+}
+
+/// The column name of a projection that selects a single top-level column.
+fn single_column_name(projection: &Projection) -> Option<&str> {
+    match projection {
+        Projection::Path(CellPath { members }) => match &members[..] {
+            [PathMember::String { val, .. }] => Some(val.as_str()),
+            _ => None,
+        },
+        Projection::Computed { .. } => None,
+    }
+}
+
+/// Resolve requested top-level column names against the input's actual
+/// columns case-insensitively, rewriting each path's first member to the
+/// actual column's casing so the output preserves it. Errors when a requested
+/// name matches two columns differing only by case, naming both.
+fn resolve_columns_insensitively(
+    columns: &mut [Projection],
+    input_columns: &[String],
+) -> Result<(), ShellError> {
+    for projection in columns {
+        if let Projection::Path(CellPath { members }) = projection {
+            if let Some(PathMember::String { val, span, .. }) = members.first_mut() {
+                let mut matches = input_columns
+                    .iter()
+                    .filter(|col| col.to_lowercase() == val.to_lowercase());
+                if let Some(first) = matches.next() {
+                    if let Some(second) = matches.next() {
+                        return Err(ShellError::GenericError(
+                            "Ambiguous column name".into(),
+                            format!("both '{first}' and '{second}' match '{val}'"),
+                            Some(*span),
+                            Some(
+                                "columns differing only by case cannot be selected with --ignore-case"
+                                    .into(),
+                            ),
+                            Vec::new(),
+                        ));
+                    }
+                    *val = first.clone();
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reject projections whose output column names collide, which would build
+/// records with duplicate columns. Identical cell paths are deduplicated
+/// before this point, so a collision means two different sources normalized to
+/// the same name: a flattened path (`a.b` -> `a_b`) shadowing a real column,
+/// or a computed column named (via `--as`) after a selected one. This mirrors
+/// the duplicate-field check in record literal evaluation.
+fn check_duplicate_output_columns(columns: &[Projection], fallback: Span) -> Result<(), ShellError> {
+    let mut seen: Vec<(String, Span)> = vec![];
+    for projection in columns {
+        let name = match projection {
+            Projection::Path(path) => output_column_name(path),
+            Projection::Computed { name, .. } => name.clone(),
+        };
+        let span = match projection {
+            Projection::Path(CellPath { members }) => match members.first() {
+                Some(PathMember::String { span, .. }) | Some(PathMember::Int { span, .. }) => *span,
+                None => fallback,
+            },
+            Projection::Computed { .. } => fallback,
+        };
+        if let Some((_, first_use)) = seen.iter().find(|(seen_name, _)| seen_name == &name) {
+            return Err(ShellError::ColumnDefinedTwice {
+                second_use: span,
+                first_use: *first_use,
+            });
+        }
+        seen.push((name, span));
+    }
+    Ok(())
+}
+
+/// Output column name for a selected cell path. A single string member keeps
+/// its literal name, which may legitimately contain dots (e.g. `config.toml`);
+/// only genuine multi-member paths get their separators replaced.
+fn output_column_name(path: &CellPath) -> String {
+    match &path.members[..] {
+        [PathMember::String { val, .. }] => val.clone(),
+        _ => path.into_string().replace('.', "_"),
+    }
+}
+
+/// Records a row in the set of rows already emitted, keyed by a serialized
+/// representation (`Value` itself is not hashable). Returns whether this is the
+/// first time the row has been seen.
+fn row_is_first_seen(row: &Value, seen_rows: &mut HashSet<String>) -> Result<bool, ShellError> {
+    let key = value_to_string(row, Span::unknown(), 0, None)?;
+    Ok(seen_rows.insert(key))
+}
+
+/// Evaluates a computed-column closure with `row` both as the first positional
+/// argument and as pipeline input, wrapping any failure with the row's span.
+fn eval_computed_cell(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    closure: &Closure,
+    row: &Value,
+    span: Span,
+) -> Result<Value, ShellError> {
+    let block = engine_state.get_block(closure.block_id);
+    let mut callee_stack = stack.captures_to_stack(&closure.captures);
+
+    if let Some(var) = block.signature.get_positional(0) {
+        if let Some(var_id) = &var.var_id {
+            callee_stack.add_var(*var_id, row.clone());
+        }
+    }
+
+    eval_block(
+        engine_state,
+        &mut callee_stack,
+        block,
+        row.clone().into_pipeline_data(),
+        call.redirect_stdout,
+        call.redirect_stderr,
+    )
+    .map(|data| data.into_value(span))
+    .map_err(|err| ShellError::EvalBlockWithInput(row.span(), vec![err]))
+}
+
 struct NthIterator {
     input: PipelineIterator,
     rows: std::iter::Peekable<std::collections::btree_set::IntoIter<usize>>,
@@ -1,7 +1,7 @@
 mod command;
 mod csv;
 mod delimited;
-mod json;
+pub(crate) mod json;
 mod nuon;
 mod ods;
 mod ssv;
@@ -0,0 +1,179 @@
+use nu_engine::{eval_block_with_early_return, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math accumulate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math accumulate")
+            .input_output_types(vec![
+                (Type::List(Box::new(Type::Any)), Type::List(Box::new(Type::Any))),
+                (Type::Range, Type::List(Box::new(Type::Any))),
+            ])
+            .named(
+                "initial",
+                SyntaxShape::Any,
+                "initial accumulator state (defaults to the first element)",
+                Some('i'),
+            )
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any, SyntaxShape::Any])),
+                "the accumulating closure, receiving the accumulator and the current element",
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Scan a list with an accumulator closure, returning every intermediate state."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Like `reduce`, but the output keeps one accumulator state per input element.
+Without `--initial` the first element seeds the accumulator and is emitted unchanged."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["scan", "fold", "reduce", "cumulative"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "[1 2 3 4] | math accumulate {|acc, it| $acc + $it }",
+                description: "Compute the running sum of a list",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(1),
+                    Value::test_int(3),
+                    Value::test_int(6),
+                    Value::test_int(10),
+                ])),
+            },
+            Example {
+                example: "[1 2 3] | math accumulate --initial 10 {|acc, it| $acc + $it }",
+                description: "Seed the accumulator with an initial state",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(11),
+                    Value::test_int(13),
+                    Value::test_int(16),
+                ])),
+            },
+            Example {
+                example: "[2 3 4] | math accumulate {|acc, it| $acc * $it }",
+                description: "Compute the running product of a list",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(2),
+                    Value::test_int(6),
+                    Value::test_int(24),
+                ])),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let initial: Option<Value> = call.get_flag(engine_state, stack, "initial")?;
+        let capture_block: Closure = call.req(engine_state, stack, 0)?;
+        let mut stack = stack.captures_to_stack(&capture_block.captures);
+        let block = engine_state.get_block(capture_block.block_id);
+        let ctrlc = engine_state.ctrlc.clone();
+
+        let orig_env_vars = stack.env_vars.clone();
+        let orig_env_hidden = stack.env_hidden.clone();
+
+        let redirect_stdout = call.redirect_stdout;
+        let redirect_stderr = call.redirect_stderr;
+
+        let mut input_iter = input.into_iter();
+
+        let mut states: Vec<Value> = vec![];
+        let mut acc = if let Some(val) = initial {
+            val
+        } else if let Some(val) = input_iter.next() {
+            // Without a seed the first element becomes the first state.
+            states.push(val.clone());
+            val
+        } else {
+            return Err(ShellError::GenericError(
+                "Expected input".to_string(),
+                "needs input".to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            ));
+        };
+
+        for x in input_iter {
+            // with_env() is used here to ensure that each iteration uses
+            // a different set of environment variables.
+            // Hence, a 'cd' in the first loop won't affect the next loop.
+            stack.with_env(&orig_env_vars, &orig_env_hidden);
+
+            let element_span = x.span();
+
+            // Accumulator argument
+            if let Some(var) = block.signature.get_positional(0) {
+                if let Some(var_id) = &var.var_id {
+                    stack.add_var(*var_id, acc.clone());
+                }
+            }
+
+            // Element argument
+            if let Some(var) = block.signature.get_positional(1) {
+                if let Some(var_id) = &var.var_id {
+                    stack.add_var(*var_id, x);
+                }
+            }
+
+            acc = eval_block_with_early_return(
+                engine_state,
+                &mut stack,
+                block,
+                PipelineData::empty(),
+                redirect_stdout,
+                redirect_stderr,
+            )
+            // point closure failures back at the element being accumulated
+            .map_err(|err| ShellError::EvalBlockWithInput(element_span, vec![err]))?
+            .into_value(span);
+
+            states.push(acc.clone());
+
+            if nu_utils::ctrl_c::was_pressed(&ctrlc) {
+                break;
+            }
+        }
+
+        Ok(Value::list(states, span).into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,225 @@
+use super::outliers::coerce_float;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    record, Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature,
+    Span, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math bucketize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math bucketize")
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Number)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+                (Type::Table(vec![]), Type::Table(vec![])),
+            ])
+            .required_named(
+                "breaks",
+                SyntaxShape::List(Box::new(SyntaxShape::Number)),
+                "the interval breakpoints, strictly increasing",
+                Some('b'),
+            )
+            .required_named(
+                "labels",
+                SyntaxShape::List(Box::new(SyntaxShape::Any)),
+                "one label per interval: exactly one fewer than breaks",
+                Some('l'),
+            )
+            .named(
+                "column",
+                SyntaxShape::String,
+                "for table input, the column to bucketize",
+                Some('c'),
+            )
+            .named(
+                "as",
+                SyntaxShape::String,
+                "name of the label column added in table mode (default 'category')",
+                None,
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Assign each numeric value the label of the interval it falls in."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The breaks `[b0 b1 .. bn]` define the half-open intervals `[b0, b1)` up to
+`[b(n-1), bn)`, so there must be exactly one label fewer than breaks. Values
+below the first break or at/above the last one are labeled null. This differs
+from `histogram`, which counts per bin; `math bucketize` labels each value."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["bin", "bucket", "categorize", "cut", "histogram"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let breaks: Vec<Value> = call
+            .get_flag(engine_state, stack, "breaks")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "breaks".into(),
+                span: head,
+            })?;
+        let labels: Vec<Value> = call
+            .get_flag(engine_state, stack, "labels")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "labels".into(),
+                span: head,
+            })?;
+        let column: Option<String> = call.get_flag(engine_state, stack, "column")?;
+        let as_name: Option<String> = call.get_flag(engine_state, stack, "as")?;
+
+        let breaks = breaks
+            .iter()
+            .map(|b| coerce_float(b, head))
+            .collect::<Result<Vec<f64>, ShellError>>()?;
+        if breaks.len() < 2 {
+            return Err(ShellError::IncorrectValue {
+                msg: "at least two breaks are required".into(),
+                val_span: head,
+                call_span: head,
+            });
+        }
+        if breaks.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(ShellError::IncorrectValue {
+                msg: "breaks must be strictly increasing".into(),
+                val_span: head,
+                call_span: head,
+            });
+        }
+        if labels.len() != breaks.len() - 1 {
+            return Err(ShellError::IncorrectValue {
+                msg: format!(
+                    "expected {} labels for {} breaks, got {}",
+                    breaks.len() - 1,
+                    breaks.len(),
+                    labels.len()
+                ),
+                val_span: head,
+                call_span: head,
+            });
+        }
+
+        let ctrlc = engine_state.ctrlc.clone();
+        let metadata = input.metadata();
+        let span = input.span().unwrap_or(head);
+        let values: Vec<Value> = input.into_iter().collect();
+
+        let output = if let Some(column) = column {
+            let as_name = as_name.unwrap_or_else(|| "category".to_string());
+            values
+                .into_iter()
+                .map(|row| label_row(row, &column, &as_name, &breaks, &labels, span, head))
+                .collect::<Result<Vec<Value>, ShellError>>()?
+        } else {
+            values
+                .into_iter()
+                .map(|value| Ok(label_for(coerce_float(&value, head)?, &breaks, &labels, head)))
+                .collect::<Result<Vec<Value>, ShellError>>()?
+        };
+
+        Ok(output.into_pipeline_data(ctrlc).set_metadata(metadata))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Label values by the interval they fall in",
+                example: "[5 15 25] | math bucketize --breaks [0 10 20] --labels [low high]",
+                result: Some(Value::test_list(vec![
+                    Value::test_string("low"),
+                    Value::test_string("high"),
+                    Value::test_nothing(),
+                ])),
+            },
+            Example {
+                description: "Add a category column to a table",
+                example: "[[size]; [5] [15]] | math bucketize --breaks [0 10 20] --labels [small big] --column size",
+                result: Some(Value::test_list(vec![
+                    Value::test_record(record! {
+                        "size" => Value::test_int(5),
+                        "category" => Value::test_string("small"),
+                    }),
+                    Value::test_record(record! {
+                        "size" => Value::test_int(15),
+                        "category" => Value::test_string("big"),
+                    }),
+                ])),
+            },
+        ]
+    }
+}
+
+/// The label of the half-open interval containing `x`, or null when `x` lies
+/// outside the breaks.
+fn label_for(x: f64, breaks: &[f64], labels: &[Value], head: Span) -> Value {
+    match breaks.windows(2).position(|pair| pair[0] <= x && x < pair[1]) {
+        Some(interval) => labels[interval].clone(),
+        None => Value::nothing(head),
+    }
+}
+
+/// Bucketize one table row: read `column`, append the label as `as_name`.
+fn label_row(
+    row: Value,
+    column: &str,
+    as_name: &str,
+    breaks: &[f64],
+    labels: &[Value],
+    span: Span,
+    head: Span,
+) -> Result<Value, ShellError> {
+    let row_span = row.span();
+    match row {
+        Value::Record { val: mut record, .. } => {
+            let cell = record.get(column).ok_or_else(|| ShellError::CantFindColumn {
+                col_name: column.into(),
+                span: row_span,
+                src_span: span,
+            })?;
+            let label = label_for(coerce_float(cell, head)?, breaks, labels, head);
+            record.push(as_name.to_string(), label);
+            Ok(Value::record(record, row_span))
+        }
+        Value::Error { error, .. } => Err(*error),
+        other => Err(ShellError::UnsupportedInput(
+            "Only tables are supported with --column".into(),
+            "value originates from here".into(),
+            head,
+            other.span(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,154 @@
+use super::outliers::coerce_float;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math covariance"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math covariance")
+            .input_output_types(vec![(Type::Table(vec![]), Type::Number)])
+            .required("first", SyntaxShape::String, "the first column name")
+            .required("second", SyntaxShape::String, "the second column name")
+            .switch(
+                "sample",
+                "compute the sample covariance (dividing by n - 1) instead of the population covariance",
+                Some('s'),
+            )
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Returns the covariance of two numeric table columns."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Unlike a correlation, the covariance is not normalized: its magnitude depends on the units of both columns."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["variance", "correlation", "spread", "statistics"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let first_col: String = call.req(engine_state, stack, 0)?;
+        let second_col: String = call.req(engine_state, stack, 1)?;
+        let sample = call.has_flag("sample");
+
+        let span = input.span().unwrap_or(head);
+        let mut first = vec![];
+        let mut second = vec![];
+        for val in input.into_iter() {
+            match val {
+                Value::Record { ref val, .. } => {
+                    if let Some(value) = val.get(&first_col) {
+                        first.push(coerce_float(value, head)?);
+                    }
+                    if let Some(value) = val.get(&second_col) {
+                        second.push(coerce_float(value, head)?);
+                    }
+                }
+                Value::Error { error, .. } => return Err(*error),
+                other => {
+                    return Err(ShellError::UnsupportedInput(
+                        "Only tables are supported".into(),
+                        "value originates from here".into(),
+                        head,
+                        other.span(),
+                    ))
+                }
+            }
+        }
+
+        if first.len() != second.len() {
+            return Err(ShellError::IncorrectValue {
+                msg: format!(
+                    "column '{}' has {} values but column '{}' has {}",
+                    first_col,
+                    first.len(),
+                    second_col,
+                    second.len()
+                ),
+                val_span: span,
+                call_span: head,
+            });
+        }
+        if first.is_empty() {
+            return Err(ShellError::IncorrectValue {
+                msg: format!("no values found in columns '{first_col}' and '{second_col}'"),
+                val_span: span,
+                call_span: head,
+            });
+        }
+        if sample && first.len() < 2 {
+            return Err(ShellError::IncorrectValue {
+                msg: "the sample covariance needs at least two rows".into(),
+                val_span: span,
+                call_span: head,
+            });
+        }
+
+        let n = first.len() as f64;
+        let mean_first = first.iter().sum::<f64>() / n;
+        let mean_second = second.iter().sum::<f64>() / n;
+        let mean_product = first
+            .iter()
+            .zip(&second)
+            .map(|(x, y)| x * y)
+            .sum::<f64>()
+            / n;
+
+        // mean of products minus product of means; rescale for the sample
+        // estimator, which divides by n - 1 instead of n
+        let mut covariance = mean_product - mean_first * mean_second;
+        if sample {
+            covariance *= n / (n - 1.0);
+        }
+
+        Ok(Value::float(covariance, head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Compute the population covariance of two columns",
+                example: "[[x y]; [0 0] [2 4]] | math covariance x y",
+                result: Some(Value::test_float(2.0)),
+            },
+            Example {
+                description: "Compute the sample covariance instead",
+                example: "[[x y]; [0 0] [2 4]] | math covariance x y --sample",
+                result: Some(Value::test_float(4.0)),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
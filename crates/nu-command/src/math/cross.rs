@@ -0,0 +1,111 @@
+use super::outliers::coerce_float;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math cross"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math cross")
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::Number)),
+                Type::List(Box::new(Type::Number)),
+            )])
+            .required(
+                "other",
+                SyntaxShape::List(Box::new(SyntaxShape::Number)),
+                "the second vector, also of exactly 3 elements",
+            )
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Returns the cross product of two 3-element numeric lists."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["vector", "product", "outer"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let other: Vec<Value> = call.req(engine_state, stack, 0)?;
+        let other_span = call
+            .positional_nth(0)
+            .map(|expr| expr.span)
+            .unwrap_or(head);
+
+        let span = input.span().unwrap_or(head);
+        let values: Vec<Value> = input.into_iter().collect();
+
+        let lhs = as_vector3(&values, span, head)?;
+        let rhs = as_vector3(&other, other_span, head)?;
+
+        let result = vec![
+            Value::float(lhs[1] * rhs[2] - lhs[2] * rhs[1], head),
+            Value::float(lhs[2] * rhs[0] - lhs[0] * rhs[2], head),
+            Value::float(lhs[0] * rhs[1] - lhs[1] * rhs[0], head),
+        ];
+
+        Ok(Value::list(result, head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Compute the cross product of two unit vectors",
+            example: "[1 0 0] | math cross [0 1 0]",
+            result: Some(Value::test_list(vec![
+                Value::test_float(0.0),
+                Value::test_float(0.0),
+                Value::test_float(1.0),
+            ])),
+        }]
+    }
+}
+
+fn as_vector3(values: &[Value], span: Span, head: Span) -> Result<[f64; 3], ShellError> {
+    if values.len() != 3 {
+        return Err(ShellError::IncorrectValue {
+            msg: format!(
+                "the cross product requires vectors of exactly 3 elements, got {}",
+                values.len()
+            ),
+            val_span: span,
+            call_span: head,
+        });
+    }
+
+    Ok([
+        coerce_float(&values[0], head)?,
+        coerce_float(&values[1], head)?,
+        coerce_float(&values[2], head)?,
+    ])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
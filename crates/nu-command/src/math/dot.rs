@@ -0,0 +1,93 @@
+use super::outliers::coerce_float;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math dot"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math dot")
+            .input_output_types(vec![(Type::List(Box::new(Type::Number)), Type::Number)])
+            .required(
+                "other",
+                SyntaxShape::List(Box::new(SyntaxShape::Number)),
+                "the second vector, of the same length as the input",
+            )
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Returns the dot product of two equal-length numeric lists."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["vector", "product", "scalar", "inner"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let other: Vec<Value> = call.req(engine_state, stack, 0)?;
+        let other_span = call
+            .positional_nth(0)
+            .map(|expr| expr.span)
+            .unwrap_or(head);
+
+        let span = input.span().unwrap_or(head);
+        let values: Vec<Value> = input.into_iter().collect();
+
+        if values.len() != other.len() {
+            return Err(ShellError::IncorrectValue {
+                msg: format!(
+                    "expected a vector of length {}, got length {}",
+                    values.len(),
+                    other.len()
+                ),
+                val_span: other_span,
+                call_span: span,
+            });
+        }
+
+        let mut total = 0.0;
+        for (lhs, rhs) in values.iter().zip(&other) {
+            total += coerce_float(lhs, head)? * coerce_float(rhs, head)?;
+        }
+
+        Ok(Value::float(total, head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Compute the dot product of two vectors",
+            example: "[1 2 3] | math dot [4 5 6]",
+            result: Some(Value::test_float(32.0)),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
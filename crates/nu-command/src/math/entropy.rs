@@ -0,0 +1,158 @@
+use crate::math::utils::run_with_function;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
+};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+/// Frequency-map key: `Value` is neither `Hash` nor `Eq`, so hash the raw
+/// representation together with the type (floats by their bit pattern).
+#[derive(Hash, Eq, PartialEq)]
+pub(super) enum DistributionKey {
+    Int(i64),
+    FloatBits(u64),
+    Duration(i64),
+    Filesize(i64),
+    String(String),
+    Bool(bool),
+}
+
+impl DistributionKey {
+    pub(super) fn for_value(value: &Value, head: Span) -> Result<Self, ShellError> {
+        match value {
+            Value::Int { val, .. } => Ok(DistributionKey::Int(*val)),
+            Value::Float { val, .. } => Ok(DistributionKey::FloatBits(val.to_bits())),
+            Value::Duration { val, .. } => Ok(DistributionKey::Duration(*val)),
+            Value::Filesize { val, .. } => Ok(DistributionKey::Filesize(*val)),
+            Value::String { val, .. } => Ok(DistributionKey::String(val.clone())),
+            Value::Bool { val, .. } => Ok(DistributionKey::Bool(*val)),
+            Value::Error { error, .. } => Err(*error.clone()),
+            other => Err(ShellError::UnsupportedInput(
+                "Unable to give a result with this input".to_string(),
+                "value originates from here".into(),
+                head,
+                other.span(),
+            )),
+        }
+    }
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math entropy"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math entropy")
+            .input_output_types(vec![
+                (Type::List(Box::new(Type::Any)), Type::Float),
+                (Type::Table(vec![]), Type::Record(vec![])),
+            ])
+            .named(
+                "base",
+                SyntaxShape::Number,
+                "logarithm base for the result (default 2, i.e. bits)",
+                Some('b'),
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Returns the Shannon entropy of the distribution of a list's values."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each distinct value contributes `-p log_b p`, where `p` is its relative frequency.
+The default base 2 measures the result in bits; a uniform distribution over `n`
+distinct values yields `log_b n`. A single-valued list has an entropy of 0."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["information", "shannon", "distribution"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let base: Option<Spanned<f64>> = call.get_flag(engine_state, stack, "base")?;
+        if let Some(base) = &base {
+            if base.item <= 0.0 || base.item == 1.0 {
+                return Err(ShellError::IncorrectValue {
+                    msg: "base must be a positive number other than 1".to_string(),
+                    val_span: base.span,
+                    call_span: call.head,
+                });
+            }
+        }
+        let base = base.map(|base| base.item).unwrap_or(2.0);
+        run_with_function(call, input, move |values, span, head| {
+            entropy(values, span, head, base)
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Entropy of a uniform distribution over 4 values is log2(4) bits",
+                example: "[a b c d] | math entropy",
+                result: Some(Value::test_float(2.0)),
+            },
+            Example {
+                description: "Entropy of a single-valued list is 0",
+                example: "[5 5 5] | math entropy",
+                result: Some(Value::test_float(0.0)),
+            },
+            Example {
+                description: "Use a different logarithm base",
+                example: "[1 1 2 2] | math entropy --base 4",
+                result: Some(Value::test_float(0.5)),
+            },
+        ]
+    }
+}
+
+fn entropy(values: &[Value], _span: Span, head: Span, base: f64) -> Result<Value, ShellError> {
+    let mut frequency_map: HashMap<DistributionKey, i64> = HashMap::new();
+    for value in values {
+        let key = DistributionKey::for_value(value, head)?;
+        *frequency_map.entry(key).or_insert(0) += 1;
+    }
+
+    let total = values.len() as f64;
+    let sum: f64 = frequency_map
+        .values()
+        .map(|frequency| {
+            let p = *frequency as f64 / total;
+            p * (p.ln() / base.ln())
+        })
+        .sum();
+
+    // negating the (non-positive) sum can produce -0.0 for certain lists
+    let entropy = -sum;
+    Ok(Value::float(
+        if entropy == 0.0 { 0.0 } else { entropy },
+        head,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,175 @@
+use super::entropy::DistributionKey;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    record, Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature,
+    Type, Value,
+};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math frequency"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math frequency")
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::Any)),
+                Type::Table(vec![]),
+            )])
+            .switch(
+                "by-count",
+                "sort by descending count instead of by value",
+                Some('c'),
+            )
+            .switch(
+                "normalize",
+                "return relative frequencies (fractions summing to 1) instead of counts",
+                Some('n'),
+            )
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Returns the frequency table of a list as `{value, count}` records."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Unlike `math mode`, which keeps only the most frequent values, this returns one
+row per distinct value. Rows are sorted by value; `--by-count` sorts by
+descending count instead, breaking ties by value. An empty list produces an
+empty table."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["count", "distribution", "histogram", "tally"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let by_count = call.has_flag("by-count");
+        let normalize = call.has_flag("normalize");
+
+        let ctrlc = engine_state.ctrlc.clone();
+        let metadata = input.metadata();
+        let values: Vec<Value> = input.into_iter().collect();
+
+        // Group in one pass: the key map only points into `pairs`, which keeps
+        // the first-seen `Value` of each group for the output.
+        let mut seen: HashMap<DistributionKey, usize> = HashMap::new();
+        let mut pairs: Vec<(Value, i64)> = vec![];
+        for value in &values {
+            let key = DistributionKey::for_value(value, head)?;
+            match seen.get(&key) {
+                Some(index) => pairs[*index].1 += 1,
+                None => {
+                    seen.insert(key, pairs.len());
+                    pairs.push((value.clone(), 1));
+                }
+            }
+        }
+
+        let by_value =
+            |a: &Value, b: &Value| a.partial_cmp(b).unwrap_or(Ordering::Equal);
+        if by_count {
+            pairs.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| by_value(&a.0, &b.0)));
+        } else {
+            pairs.sort_by(|a, b| by_value(&a.0, &b.0));
+        }
+
+        let total = values.len() as f64;
+        let output: Vec<Value> = pairs
+            .into_iter()
+            .map(|(value, count)| {
+                let count = if normalize {
+                    Value::float(count as f64 / total, head)
+                } else {
+                    Value::int(count, head)
+                };
+                Value::record(
+                    record! {
+                        "value" => value,
+                        "count" => count,
+                    },
+                    head,
+                )
+            })
+            .collect();
+
+        Ok(output.into_pipeline_data(ctrlc).set_metadata(metadata))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Count how often each value occurs",
+                example: "[3 1 3 2 3 2] | math frequency",
+                result: Some(Value::test_list(vec![
+                    Value::test_record(record! {
+                        "value" => Value::test_int(1),
+                        "count" => Value::test_int(1),
+                    }),
+                    Value::test_record(record! {
+                        "value" => Value::test_int(2),
+                        "count" => Value::test_int(2),
+                    }),
+                    Value::test_record(record! {
+                        "value" => Value::test_int(3),
+                        "count" => Value::test_int(3),
+                    }),
+                ])),
+            },
+            Example {
+                description: "Sort the most frequent values first",
+                example: "[a b a] | math frequency --by-count",
+                result: Some(Value::test_list(vec![
+                    Value::test_record(record! {
+                        "value" => Value::test_string("a"),
+                        "count" => Value::test_int(2),
+                    }),
+                    Value::test_record(record! {
+                        "value" => Value::test_string("b"),
+                        "count" => Value::test_int(1),
+                    }),
+                ])),
+            },
+            Example {
+                description: "Return relative frequencies instead of counts",
+                example: "[1 1 2 2] | math frequency --normalize",
+                result: Some(Value::test_list(vec![
+                    Value::test_record(record! {
+                        "value" => Value::test_int(1),
+                        "count" => Value::test_float(0.5),
+                    }),
+                    Value::test_record(record! {
+                        "value" => Value::test_int(2),
+                        "count" => Value::test_float(0.5),
+                    }),
+                ])),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
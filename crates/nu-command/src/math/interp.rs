@@ -0,0 +1,226 @@
+use super::outliers::coerce_float;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math interp"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math interp")
+            .input_output_types(vec![(Type::Table(vec![]), Type::Any)])
+            .required_named(
+                "x-col",
+                SyntaxShape::String,
+                "the column holding the x coordinates",
+                None,
+            )
+            .required_named(
+                "y-col",
+                SyntaxShape::String,
+                "the column holding the y coordinates",
+                None,
+            )
+            .switch(
+                "extrapolate",
+                "extend the outermost segments instead of erroring on out-of-range queries",
+                Some('e'),
+            )
+            .required(
+                "x",
+                SyntaxShape::OneOf(vec![
+                    SyntaxShape::Number,
+                    SyntaxShape::List(Box::new(SyntaxShape::Number)),
+                ]),
+                "the x value (or list of x values) to interpolate at",
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Linearly interpolate a y value from paired x/y columns of a table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The data points are sorted by x before the bracketing pair is located, so the
+input does not need to be ordered, but x values must be distinct. Queries
+outside the data range error unless `--extrapolate` extends the outermost
+segments. A list of query points returns a list of results."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["interpolate", "linear", "lookup", "lerp"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let x_col: String = call
+            .get_flag(engine_state, stack, "x-col")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "x-col".into(),
+                span: head,
+            })?;
+        let y_col: String = call
+            .get_flag(engine_state, stack, "y-col")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "y-col".into(),
+                span: head,
+            })?;
+        let extrapolate = call.has_flag("extrapolate");
+        let query: Value = call.req(engine_state, stack, 0)?;
+
+        let span = input.span().unwrap_or(head);
+        let values: Vec<Value> = input.into_iter().collect();
+        let points = collect_points(&values, &x_col, &y_col, span, head)?;
+
+        match query {
+            Value::List { vals, .. } => {
+                let results = vals
+                    .iter()
+                    .map(|x| interp_at(&points, x, extrapolate, head))
+                    .collect::<Result<Vec<Value>, ShellError>>()?;
+                Ok(Value::list(results, head).into_pipeline_data())
+            }
+            x => Ok(interp_at(&points, &x, extrapolate, head)?.into_pipeline_data()),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Interpolate a y value between two data points",
+                example: "[[xs ys]; [0 0] [1 10] [2 20]] | math interp --x-col xs --y-col ys 0.5",
+                result: Some(Value::test_float(5.0)),
+            },
+            Example {
+                description: "Interpolate at several query points at once",
+                example: "[[xs ys]; [0 0] [1 10] [2 20]] | math interp --x-col xs --y-col ys [0.5 1.5]",
+                result: Some(Value::test_list(vec![
+                    Value::test_float(5.0),
+                    Value::test_float(15.0),
+                ])),
+            },
+            Example {
+                description: "Extend the last segment beyond the data range",
+                example: "[[xs ys]; [0 0] [1 10]] | math interp --x-col xs --y-col ys 2 --extrapolate",
+                result: Some(Value::test_float(20.0)),
+            },
+        ]
+    }
+}
+
+/// Extract the (x, y) pairs from the input rows and sort them by x.
+fn collect_points(
+    values: &[Value],
+    x_col: &str,
+    y_col: &str,
+    span: Span,
+    head: Span,
+) -> Result<Vec<(f64, f64)>, ShellError> {
+    let mut points = Vec::with_capacity(values.len());
+    for val in values {
+        match val {
+            Value::Record { val: record, .. } => {
+                let x = record.get(x_col).ok_or_else(|| ShellError::CantFindColumn {
+                    col_name: x_col.into(),
+                    span: val.span(),
+                    src_span: span,
+                })?;
+                let y = record.get(y_col).ok_or_else(|| ShellError::CantFindColumn {
+                    col_name: y_col.into(),
+                    span: val.span(),
+                    src_span: span,
+                })?;
+                points.push((coerce_float(x, head)?, coerce_float(y, head)?));
+            }
+            Value::Error { error, .. } => return Err(*error.clone()),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "Only tables are supported".into(),
+                    "value originates from here".into(),
+                    head,
+                    other.span(),
+                ))
+            }
+        }
+    }
+
+    if points.len() < 2 {
+        return Err(ShellError::IncorrectValue {
+            msg: "interpolation requires at least two data points".into(),
+            val_span: span,
+            call_span: head,
+        });
+    }
+
+    points.sort_by(|a, b| a.0.total_cmp(&b.0));
+    if points.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+        return Err(ShellError::IncorrectValue {
+            msg: "the x column contains duplicate values".into(),
+            val_span: span,
+            call_span: head,
+        });
+    }
+
+    Ok(points)
+}
+
+/// Interpolate the y value at `query`, using the bracketing pair of the sorted
+/// points, or the outermost segment when extrapolating.
+fn interp_at(
+    points: &[(f64, f64)],
+    query: &Value,
+    extrapolate: bool,
+    head: Span,
+) -> Result<Value, ShellError> {
+    let x = coerce_float(query, head)?;
+
+    let (min, _) = points[0];
+    let (max, _) = points[points.len() - 1];
+    if !extrapolate && (x < min || x > max) {
+        return Err(ShellError::IncorrectValue {
+            msg: format!("x={x} is outside the data range {min} to {max}; use --extrapolate to extend it"),
+            val_span: query.span(),
+            call_span: head,
+        });
+    }
+
+    // Pick the segment whose x range brackets the query; out-of-range queries
+    // (only reachable with --extrapolate) reuse the outermost segment.
+    let segment = points
+        .windows(2)
+        .position(|pair| x <= pair[1].0)
+        .unwrap_or(points.len() - 2);
+    let (x0, y0) = points[segment];
+    let (x1, y1) = points[segment + 1];
+
+    Ok(Value::float(y0 + (y1 - y0) * (x - x0) / (x1 - x0), head))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,218 @@
+use super::outliers::coerce_float;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+/// What kind of value the endpoints are, and therefore what the result is.
+#[derive(Clone, Copy, PartialEq)]
+enum LerpKind {
+    Number,
+    Duration,
+    Filesize,
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math lerp"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math lerp")
+            .input_output_types(vec![
+                (Type::Number, Type::Any),
+                (
+                    Type::List(Box::new(Type::Number)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+            ])
+            .required_named(
+                "from",
+                SyntaxShape::Any,
+                "the value a fraction of 0 maps to",
+                Some('f'),
+            )
+            .required_named(
+                "to",
+                SyntaxShape::Any,
+                "the value a fraction of 1 maps to",
+                Some('t'),
+            )
+            .switch(
+                "clamp",
+                "clamp fractions into 0..1 instead of extrapolating",
+                None,
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Linearly interpolate between two values by the piped-in fraction(s)."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Fractions outside 0..1 extrapolate beyond the endpoints unless `--clamp` is
+given. Both endpoints must be the same kind of value: two numbers, two
+durations or two file sizes, and the result keeps that kind. Unlike
+`math interp` this needs no data table, just the two endpoints."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["interpolate", "linear", "mix", "blend"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let from: Value = call
+            .get_flag(engine_state, stack, "from")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "from".into(),
+                span: head,
+            })?;
+        let to: Value = call
+            .get_flag(engine_state, stack, "to")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "to".into(),
+                span: head,
+            })?;
+        let clamp = call.has_flag("clamp");
+
+        let from_kind = endpoint_kind(&from, head)?;
+        let to_kind = endpoint_kind(&to, head)?;
+        if from_kind != to_kind {
+            return Err(ShellError::IncorrectValue {
+                msg: "from and to must both be numbers, both durations or both file sizes"
+                    .into(),
+                val_span: to.span(),
+                call_span: head,
+            });
+        }
+        let from = coerce_float(&from, head)?;
+        let to = coerce_float(&to, head)?;
+
+        let ctrlc = engine_state.ctrlc.clone();
+        let metadata = input.metadata();
+        match input {
+            PipelineData::Value(Value::List { vals, .. }, ..) => {
+                let output = vals
+                    .into_iter()
+                    .map(|fraction| lerp(&fraction, from, to, from_kind, clamp, head))
+                    .collect::<Result<Vec<Value>, ShellError>>()?;
+                Ok(output.into_pipeline_data(ctrlc).set_metadata(metadata))
+            }
+            PipelineData::ListStream(..) => {
+                let values: Vec<Value> = input.into_iter().collect();
+                let output = values
+                    .iter()
+                    .map(|fraction| lerp(fraction, from, to, from_kind, clamp, head))
+                    .collect::<Result<Vec<Value>, ShellError>>()?;
+                Ok(output.into_pipeline_data(ctrlc).set_metadata(metadata))
+            }
+            input => {
+                let fraction = input.into_value(head);
+                Ok(lerp(&fraction, from, to, from_kind, clamp, head)?
+                    .into_pipeline_data()
+                    .set_metadata(metadata))
+            }
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Interpolate a quarter of the way between 0 and 100",
+                example: "0.25 | math lerp --from 0 --to 100",
+                result: Some(Value::test_float(25.0)),
+            },
+            Example {
+                description: "Interpolate a list of fractions",
+                example: "[0 0.5 1] | math lerp --from 10 --to 20",
+                result: Some(Value::test_list(vec![
+                    Value::test_float(10.0),
+                    Value::test_float(15.0),
+                    Value::test_float(20.0),
+                ])),
+            },
+            Example {
+                description: "Clamp fractions instead of extrapolating",
+                example: "1.5 | math lerp --from 0 --to 10 --clamp",
+                result: Some(Value::test_float(10.0)),
+            },
+            Example {
+                description: "Interpolate between durations",
+                example: "0.5 | math lerp --from 0sec --to 10sec",
+                result: Some(Value::test_duration(5_000_000_000)),
+            },
+        ]
+    }
+}
+
+fn endpoint_kind(value: &Value, head: Span) -> Result<LerpKind, ShellError> {
+    match value {
+        Value::Int { .. } | Value::Float { .. } => Ok(LerpKind::Number),
+        Value::Duration { .. } => Ok(LerpKind::Duration),
+        Value::Filesize { .. } => Ok(LerpKind::Filesize),
+        Value::Error { error, .. } => Err(*error.clone()),
+        other => Err(ShellError::UnsupportedInput(
+            "Only numbers, durations and file sizes are supported as endpoints".into(),
+            "value originates from here".into(),
+            head,
+            other.span(),
+        )),
+    }
+}
+
+fn lerp(
+    fraction: &Value,
+    from: f64,
+    to: f64,
+    kind: LerpKind,
+    clamp: bool,
+    head: Span,
+) -> Result<Value, ShellError> {
+    let t = match fraction {
+        Value::Int { val, .. } => *val as f64,
+        Value::Float { val, .. } => *val,
+        Value::Error { error, .. } => return Err(*error.clone()),
+        other => {
+            return Err(ShellError::UnsupportedInput(
+                "Only numbers are supported as fractions".into(),
+                "value originates from here".into(),
+                head,
+                other.span(),
+            ))
+        }
+    };
+    let t = if clamp { t.clamp(0.0, 1.0) } else { t };
+    let result = from + (to - from) * t;
+    Ok(match kind {
+        LerpKind::Number => Value::float(result, head),
+        LerpKind::Duration => Value::duration(result.round() as i64, head),
+        LerpKind::Filesize => Value::filesize(result.round() as i64, head),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
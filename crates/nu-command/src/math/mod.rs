@@ -1,36 +1,70 @@
 mod abs;
+mod accumulate;
 mod avg;
+mod bucketize;
 mod ceil;
+mod covariance;
+mod cross;
+mod dot;
+mod entropy;
 mod floor;
+mod frequency;
+mod interp;
+mod lerp;
 mod log;
 pub mod math_;
 mod max;
 mod median;
 mod min;
 mod mode;
+mod moving_average;
+mod normalize;
+mod outliers;
 mod product;
+mod rank;
 mod reducers;
 mod round;
+mod running_median;
+mod sign;
 mod sqrt;
 mod stddev;
 mod sum;
 mod utils;
 mod variance;
+mod weighted_avg;
+mod zscore;
 
 pub use abs::SubCommand as MathAbs;
+pub use accumulate::SubCommand as MathAccumulate;
 pub use avg::SubCommand as MathAvg;
+pub use bucketize::SubCommand as MathBucketize;
 pub use ceil::SubCommand as MathCeil;
+pub use covariance::SubCommand as MathCovariance;
+pub use cross::SubCommand as MathCross;
+pub use dot::SubCommand as MathDot;
+pub use entropy::SubCommand as MathEntropy;
 pub use floor::SubCommand as MathFloor;
+pub use frequency::SubCommand as MathFrequency;
+pub use interp::SubCommand as MathInterp;
+pub use lerp::SubCommand as MathLerp;
 pub use math_::MathCommand as Math;
 pub use max::SubCommand as MathMax;
 pub use median::SubCommand as MathMedian;
 pub use min::SubCommand as MathMin;
 pub use mode::SubCommand as MathMode;
+pub use moving_average::SubCommand as MathMovingAverage;
+pub use normalize::SubCommand as MathNormalize;
+pub use outliers::SubCommand as MathOutliers;
 pub use product::SubCommand as MathProduct;
+pub use rank::SubCommand as MathRank;
 pub use round::SubCommand as MathRound;
+pub use running_median::SubCommand as MathRunningMedian;
+pub use sign::SubCommand as MathSign;
 pub use sqrt::SubCommand as MathSqrt;
 pub use stddev::SubCommand as MathStddev;
 pub use sum::SubCommand as MathSum;
 pub use variance::SubCommand as MathVariance;
+pub use weighted_avg::SubCommand as MathWeightedAvg;
+pub use zscore::SubCommand as MathZscore;
 
 pub use self::log::SubCommand as MathLog;
@@ -45,6 +45,7 @@ impl Command for SubCommand {
                     Type::List(Box::new(Type::Number)),
                     Type::List(Box::new(Type::Number)),
                 ),
+                (Type::List(Box::new(Type::Number)), Type::Number),
                 (
                     Type::List(Box::new(Type::Duration)),
                     Type::List(Box::new(Type::Duration)),
@@ -55,6 +56,11 @@ impl Command for SubCommand {
                 ),
                 (Type::Table(vec![]), Type::Record(vec![])),
             ])
+            .switch(
+                "scalar",
+                "return the single mode directly when there is exactly one",
+                Some('s'),
+            )
             .allow_variants_without_examples(true)
             .category(Category::Math)
     }
@@ -63,6 +69,10 @@ impl Command for SubCommand {
         "Returns the most frequent element(s) from a list of numbers or tables."
     }
 
+    fn extra_usage(&self) -> &str {
+        "With --scalar, a unimodal input yields the mode itself instead of a one-element list; an input with tied modes still yields the full list."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["common", "often"]
     }
@@ -74,7 +84,16 @@ impl Command for SubCommand {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        run_with_function(call, input, mode)
+        let scalar = call.has_flag("scalar");
+        run_with_function(call, input, move |values, span, head| {
+            let modes = mode(values, span, head)?;
+            match modes {
+                // the flag only unwraps an unambiguous result; ties keep the
+                // list so the caller can see them
+                Value::List { ref vals, .. } if scalar && vals.len() == 1 => Ok(vals[0].clone()),
+                modes => Ok(modes),
+            }
+        })
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -87,6 +106,19 @@ impl Command for SubCommand {
                     Value::test_int(12),
                 ])),
             },
+            Example {
+                description: "Unwrap the single mode of a unimodal list",
+                example: "[1 2 2] | math mode --scalar",
+                result: Some(Value::test_int(2)),
+            },
+            Example {
+                description: "Tied modes are still returned as a list under --scalar",
+                example: "[1 1 2 2 3] | math mode --scalar",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(1),
+                    Value::test_int(2),
+                ])),
+            },
             Example {
                 description: "Compute the mode(s) of the columns of a table",
                 example: "[{a: 1 b: 3} {a: 2 b: -1} {a: 1 b: 5}] | math mode",
@@ -0,0 +1,218 @@
+use super::avg::average;
+use super::weighted_avg::weighted_average;
+use crate::math::utils::run_with_function;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math moving-average"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math moving-average")
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Number)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+                (
+                    Type::List(Box::new(Type::Duration)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+                (
+                    Type::List(Box::new(Type::Filesize)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+            ])
+            .required_named(
+                "window",
+                SyntaxShape::Int,
+                "the number of elements in each window",
+                Some('w'),
+            )
+            .named(
+                "weights",
+                SyntaxShape::List(Box::new(SyntaxShape::Number)),
+                "one weight per window element, for a weighted moving average",
+                None,
+            )
+            .switch(
+                "center",
+                "center the window on each element instead of ending it there",
+                Some('c'),
+            )
+            .switch(
+                "partial",
+                "average the partial windows at the edges instead of returning nothing",
+                Some('p'),
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Returns the average of a sliding window ending at (or centered on) each element."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Elements without a full window produce nothing unless --partial is given."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["rolling", "sliding", "window", "smooth", "mean", "statistics"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let window: Spanned<i64> = call
+            .get_flag(engine_state, stack, "window")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "window".into(),
+                span: call.head,
+            })?;
+        if window.item < 1 {
+            return Err(ShellError::IncorrectValue {
+                msg: "window must be a positive integer".into(),
+                val_span: window.span,
+                call_span: call.head,
+            });
+        }
+        let weights = match call.get_flag::<Value>(engine_state, stack, "weights")? {
+            Some(value) => {
+                let vals = value.as_list()?.to_vec();
+                if vals.len() != window.item as usize {
+                    return Err(ShellError::IncorrectValue {
+                        msg: format!(
+                            "expected {} weights to match the window, got {}",
+                            window.item,
+                            vals.len()
+                        ),
+                        val_span: value.span(),
+                        call_span: call.head,
+                    });
+                }
+                Some(vals)
+            }
+            None => None,
+        };
+        let window = window.item as usize;
+        let center = call.has_flag("center");
+        let partial = call.has_flag("partial");
+        run_with_function(call, input, move |values, span, head| {
+            moving_average(values, span, head, window, center, partial, weights.as_deref())
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Compute the moving average with a trailing window of 3",
+                example: "[1 2 3 4 5] | math moving-average --window 3",
+                result: Some(Value::test_list(vec![
+                    Value::test_nothing(),
+                    Value::test_nothing(),
+                    Value::test_int(2),
+                    Value::test_int(3),
+                    Value::test_int(4),
+                ])),
+            },
+            Example {
+                description: "Fill the ramp-up with averages of partial windows",
+                example: "[1 2 3 4 5] | math moving-average --window 3 --partial",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(1),
+                    Value::test_float(1.5),
+                    Value::test_int(2),
+                    Value::test_int(3),
+                    Value::test_int(4),
+                ])),
+            },
+            Example {
+                description: "Weight the most recent element of each window twice as much",
+                example: "[1 2 3 4 5] | math moving-average --window 3 --weights [1 1 2]",
+                result: Some(Value::test_list(vec![
+                    Value::test_nothing(),
+                    Value::test_nothing(),
+                    Value::test_float(2.25),
+                    Value::test_float(3.25),
+                    Value::test_float(4.25),
+                ])),
+            },
+        ]
+    }
+}
+
+fn moving_average(
+    values: &[Value],
+    span: Span,
+    head: Span,
+    window: usize,
+    center: bool,
+    partial: bool,
+    weights: Option<&[Value]>,
+) -> Result<Value, ShellError> {
+    if window > values.len() {
+        return Err(ShellError::IncorrectValue {
+            msg: format!(
+                "window of {} is larger than the input of {} elements",
+                window,
+                values.len()
+            ),
+            val_span: span,
+            call_span: head,
+        });
+    }
+
+    let before = if center { (window - 1) / 2 } else { window - 1 };
+    let after = window - 1 - before;
+
+    let mut averages = Vec::with_capacity(values.len());
+    for idx in 0..values.len() {
+        let start = idx.saturating_sub(before);
+        let end = usize::min(idx + after + 1, values.len());
+
+        if end - start < window && !partial {
+            averages.push(Value::nothing(head));
+            continue;
+        }
+
+        let result = match weights {
+            Some(weights) => {
+                // keep the weights aligned with the window even when an edge
+                // clips it: drop the weights of the missing positions
+                let offset = before.saturating_sub(idx);
+                let weights = &weights[offset..offset + (end - start)];
+                weighted_average(&values[start..end], weights, span, head)?
+            }
+            None => average(&values[start..end], span, head)?,
+        };
+        averages.push(result);
+    }
+
+    Ok(Value::list(averages, head))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
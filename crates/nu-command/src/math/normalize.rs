@@ -0,0 +1,139 @@
+use super::outliers::coerce_float;
+use crate::math::utils::run_with_function;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone, Copy)]
+enum Norm {
+    L1,
+    L2,
+    Max,
+}
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math normalize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math normalize")
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Number)),
+                    Type::List(Box::new(Type::Float)),
+                ),
+                (Type::Table(vec![]), Type::Record(vec![])),
+            ])
+            .named(
+                "norm",
+                SyntaxShape::String,
+                "the norm to divide by: l2 (default), l1, or max",
+                Some('n'),
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Returns the list scaled so its chosen norm is 1."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "A zero vector has no direction to preserve, so it normalizes to all zeros."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["vector", "norm", "unit", "scale"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let norm = match call.get_flag::<Spanned<String>>(engine_state, stack, "norm")? {
+            None => Norm::L2,
+            Some(flag) => match flag.item.as_str() {
+                "l1" => Norm::L1,
+                "l2" => Norm::L2,
+                "max" => Norm::Max,
+                _ => {
+                    return Err(ShellError::IncorrectValue {
+                        msg: "norm must be one of l1, l2, or max".into(),
+                        val_span: flag.span,
+                        call_span: call.head,
+                    });
+                }
+            },
+        };
+        run_with_function(call, input, move |values, span, head| {
+            normalize(values, span, head, norm)
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Scale a vector to unit length (L2 norm)",
+                example: "[3 4] | math normalize",
+                result: Some(Value::test_list(vec![
+                    Value::test_float(0.6),
+                    Value::test_float(0.8),
+                ])),
+            },
+            Example {
+                description: "Scale a vector so its absolute values sum to 1 (L1 norm)",
+                example: "[3 4] | math normalize --norm l1",
+                result: Some(Value::test_list(vec![
+                    Value::test_float(3.0 / 7.0),
+                    Value::test_float(4.0 / 7.0),
+                ])),
+            },
+        ]
+    }
+}
+
+fn normalize(values: &[Value], _span: Span, head: Span, norm: Norm) -> Result<Value, ShellError> {
+    let floats = values
+        .iter()
+        .map(|value| coerce_float(value, head))
+        .collect::<Result<Vec<f64>, ShellError>>()?;
+
+    let divisor = match norm {
+        Norm::L1 => floats.iter().map(|v| v.abs()).sum(),
+        Norm::L2 => floats.iter().map(|v| v * v).sum::<f64>().sqrt(),
+        Norm::Max => floats.iter().map(|v| v.abs()).fold(0.0, f64::max),
+    };
+
+    let normalized = if divisor == 0.0 {
+        floats.iter().map(|_| Value::float(0.0, head)).collect()
+    } else {
+        floats
+            .iter()
+            .map(|v| Value::float(v / divisor, head))
+            .collect()
+    };
+
+    Ok(Value::list(normalized, head))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,152 @@
+use super::median::median;
+use crate::math::utils::run_with_function;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+use std::cmp::Ordering;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math outliers"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math outliers")
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Number)),
+                    Type::List(Box::new(Type::Number)),
+                ),
+                (Type::Table(vec![]), Type::Record(vec![])),
+            ])
+            .named(
+                "factor",
+                SyntaxShape::Number,
+                "multiple of the IQR beyond the quartiles marking a value as an outlier (default 1.5)",
+                Some('k'),
+            )
+            .switch(
+                "indices",
+                "return the positions of the outliers instead of their values",
+                None,
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Returns the values lying below Q1 - k*IQR or above Q3 + k*IQR."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Inputs with fewer than 4 elements have no meaningful quartiles and produce an empty list."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["iqr", "quartile", "anomaly", "statistics"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let factor = call
+            .get_flag::<f64>(engine_state, stack, "factor")?
+            .unwrap_or(1.5);
+        let indices = call.has_flag("indices");
+        run_with_function(call, input, move |values, span, head| {
+            outliers(values, span, head, factor, indices)
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Find the outliers in a list of numbers",
+                example: "[1 2 3 4 5 100] | math outliers",
+                result: Some(Value::test_list(vec![Value::test_int(100)])),
+            },
+            Example {
+                description: "Find the positions of the outliers instead",
+                example: "[100 1 2 3 4 5] | math outliers --indices",
+                result: Some(Value::test_list(vec![Value::test_int(0)])),
+            },
+        ]
+    }
+}
+
+fn outliers(
+    values: &[Value],
+    span: Span,
+    head: Span,
+    factor: f64,
+    indices: bool,
+) -> Result<Value, ShellError> {
+    // Quartiles need at least two elements per half to be meaningful
+    if values.len() < 4 {
+        return Ok(Value::list(vec![], head));
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    let mid = sorted.len() / 2;
+    let upper_start = if sorted.len() % 2 == 0 { mid } else { mid + 1 };
+    let q1 = coerce_float(&median(&sorted[..mid], span, head)?, head)?;
+    let q3 = coerce_float(&median(&sorted[upper_start..], span, head)?, head)?;
+    let iqr = q3 - q1;
+
+    let lower_fence = q1 - factor * iqr;
+    let upper_fence = q3 + factor * iqr;
+
+    let mut output = vec![];
+    for (idx, value) in values.iter().enumerate() {
+        let val = coerce_float(value, head)?;
+        if val < lower_fence || val > upper_fence {
+            if indices {
+                output.push(Value::int(idx as i64, head));
+            } else {
+                output.push(value.clone());
+            }
+        }
+    }
+
+    Ok(Value::list(output, head))
+}
+
+pub(super) fn coerce_float(value: &Value, head: Span) -> Result<f64, ShellError> {
+    match value {
+        Value::Int { val, .. } | Value::Duration { val, .. } | Value::Filesize { val, .. } => {
+            Ok(*val as f64)
+        }
+        Value::Float { val, .. } => Ok(*val),
+        Value::Error { error, .. } => Err(*error.clone()),
+        other => Err(ShellError::UnsupportedInput(
+            "Unable to give a result with this input".to_string(),
+            "value originates from here".into(),
+            head,
+            other.span(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
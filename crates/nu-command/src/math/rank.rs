@@ -0,0 +1,213 @@
+use std::cmp::Ordering;
+
+use crate::math::utils::run_with_function;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    record, Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+#[derive(Clone, Copy)]
+enum RankMethod {
+    Ordinal,
+    Dense,
+    Min,
+    Max,
+    Average,
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math rank"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math rank")
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Number)),
+                    Type::List(Box::new(Type::Number)),
+                ),
+                (Type::Table(vec![]), Type::Record(vec![])),
+            ])
+            .named(
+                "method",
+                SyntaxShape::String,
+                "tie handling: ordinal (default), dense, min, max or average",
+                Some('m'),
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Returns the 1-based rank of each element of a list."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The output list is parallel to the input: each element is replaced by its
+rank in the sorted order. The default ordinal method gives tied values distinct
+consecutive ranks in input order; dense, min, max and average instead give
+every member of a tie group the same rank."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["order", "position", "ordinal", "statistics"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let method = match call.get_flag::<Spanned<String>>(engine_state, stack, "method")? {
+            Some(method) => match method.item.as_str() {
+                "ordinal" => RankMethod::Ordinal,
+                "dense" => RankMethod::Dense,
+                "min" => RankMethod::Min,
+                "max" => RankMethod::Max,
+                "average" => RankMethod::Average,
+                _ => {
+                    return Err(ShellError::IncorrectValue {
+                        msg: "method must be one of ordinal, dense, min, max or average".into(),
+                        val_span: method.span,
+                        call_span: head,
+                    })
+                }
+            },
+            None => RankMethod::Ordinal,
+        };
+        run_with_function(call, input, move |values, span, head| {
+            rank(values, span, head, method)
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Rank the elements of a list",
+                example: "[30 10 20] | math rank",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(3),
+                    Value::test_int(1),
+                    Value::test_int(2),
+                ])),
+            },
+            Example {
+                description: "Ordinal ranking gives ties distinct ranks in input order",
+                example: "[10 20 20 30] | math rank",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(1),
+                    Value::test_int(2),
+                    Value::test_int(3),
+                    Value::test_int(4),
+                ])),
+            },
+            Example {
+                description: "Dense ranking leaves no gaps after a tie",
+                example: "[10 20 20 30] | math rank --method dense",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(1),
+                    Value::test_int(2),
+                    Value::test_int(2),
+                    Value::test_int(3),
+                ])),
+            },
+            Example {
+                description: "Average ranking splits the tie's ranks evenly",
+                example: "[10 20 20 30] | math rank --method average",
+                result: Some(Value::test_list(vec![
+                    Value::test_float(1.0),
+                    Value::test_float(2.5),
+                    Value::test_float(2.5),
+                    Value::test_float(4.0),
+                ])),
+            },
+            Example {
+                description: "Rank the columns of a table",
+                example: "[{a: 2 b: 5} {a: 1 b: 9}] | math rank",
+                result: Some(Value::test_record(record! {
+                    "a" => Value::test_list(vec![Value::test_int(2), Value::test_int(1)]),
+                    "b" => Value::test_list(vec![Value::test_int(1), Value::test_int(2)]),
+                })),
+            },
+        ]
+    }
+}
+
+fn rank(
+    values: &[Value],
+    _span: Span,
+    head: Span,
+    method: RankMethod,
+) -> Result<Value, ShellError> {
+    if let Some(Err(error)) = values
+        .windows(2)
+        .map(|elem| {
+            if elem[0].partial_cmp(&elem[1]).is_none() {
+                return Err(ShellError::OperatorMismatch {
+                    op_span: head,
+                    lhs_ty: elem[0].get_type().to_string(),
+                    lhs_span: elem[0].span(),
+                    rhs_ty: elem[1].get_type().to_string(),
+                    rhs_span: elem[1].span(),
+                });
+            }
+            Ok(())
+        })
+        .find(|elem| elem.is_err())
+    {
+        return Err(error);
+    }
+
+    // A stable sort with the original index along for the ride lets the ranks
+    // be written back in input order afterwards; ordinal ties then break by
+    // input order for free.
+    let mut indexed: Vec<(usize, &Value)> = values.iter().enumerate().collect();
+    indexed.sort_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(Ordering::Equal));
+
+    let mut ranks = vec![Value::nothing(head); values.len()];
+    let mut start = 0;
+    let mut dense_rank = 0;
+    while start < indexed.len() {
+        // find the end of the tie group starting at `start`
+        let mut end = start;
+        while end + 1 < indexed.len()
+            && indexed[end + 1].1.partial_cmp(indexed[start].1) == Some(Ordering::Equal)
+        {
+            end += 1;
+        }
+        dense_rank += 1;
+        for (offset, (original_idx, _)) in indexed[start..=end].iter().enumerate() {
+            ranks[*original_idx] = match method {
+                RankMethod::Ordinal => Value::int((start + offset + 1) as i64, head),
+                RankMethod::Dense => Value::int(dense_rank, head),
+                RankMethod::Min => Value::int((start + 1) as i64, head),
+                RankMethod::Max => Value::int((end + 1) as i64, head),
+                RankMethod::Average => Value::float((start + end + 2) as f64 / 2.0, head),
+            };
+        }
+        start = end + 1;
+    }
+    Ok(Value::list(ranks, head))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -2,12 +2,21 @@ use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
 pub struct SubCommand;
 
+#[derive(Clone, Copy)]
+enum RoundMode {
+    HalfUp,
+    HalfEven,
+    Floor,
+    Ceil,
+    TowardZero,
+}
+
 impl Command for SubCommand {
     fn name(&self) -> &str {
         "math round"
@@ -29,6 +38,12 @@ impl Command for SubCommand {
                 "digits of precision",
                 Some('p'),
             )
+            .named(
+                "mode",
+                SyntaxShape::String,
+                "rounding mode: half-up (default; ties round away from zero), half-even (banker's), floor, ceil or toward-zero",
+                Some('m'),
+            )
             .category(Category::Math)
     }
 
@@ -36,8 +51,14 @@ impl Command for SubCommand {
         "Returns the input number rounded to the specified precision."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"The default half-up mode rounds ties away from zero, so both 2.5 and -2.5
+move to 3 and -3. Half-even (banker's) rounding sends ties to the nearest even
+number instead, which avoids the upward bias when summing rounded values."#
+    }
+
     fn search_terms(&self) -> Vec<&str> {
-        vec!["approx", "closest", "nearest"]
+        vec!["approx", "closest", "nearest", "banker", "truncate"]
     }
 
     fn run(
@@ -47,14 +68,32 @@ impl Command for SubCommand {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let precision_param: Option<i64> = call.get_flag(engine_state, stack, "precision")?;
         let head = call.head;
+        let precision_param: Option<i64> = call.get_flag(engine_state, stack, "precision")?;
+        let mode = match call.get_flag::<Spanned<String>>(engine_state, stack, "mode")? {
+            Some(mode) => match mode.item.as_str() {
+                "half-up" => RoundMode::HalfUp,
+                "half-even" => RoundMode::HalfEven,
+                "floor" => RoundMode::Floor,
+                "ceil" => RoundMode::Ceil,
+                "toward-zero" => RoundMode::TowardZero,
+                _ => {
+                    return Err(ShellError::IncorrectValue {
+                        msg: "mode must be one of half-up, half-even, floor, ceil or toward-zero"
+                            .into(),
+                        val_span: mode.span,
+                        call_span: head,
+                    })
+                }
+            },
+            None => RoundMode::HalfUp,
+        };
         // This doesn't match explicit nulls
         if matches!(input, PipelineData::Empty) {
             return Err(ShellError::PipelineEmpty { dst_span: head });
         }
         input.map(
-            move |value| operate(value, head, precision_param),
+            move |value| operate(value, head, precision_param, mode),
             engine_state.ctrlc.clone(),
         )
     }
@@ -93,11 +132,35 @@ impl Command for SubCommand {
                     Span::test_data(),
                 )),
             },
+            Example {
+                description: "Half-up rounds ties away from zero",
+                example: "[2.5 3.5 -2.5] | math round --mode half-up",
+                result: Some(Value::list(
+                    vec![Value::test_int(3), Value::test_int(4), Value::test_int(-3)],
+                    Span::test_data(),
+                )),
+            },
+            Example {
+                description: "Half-even (banker's) rounds ties to the nearest even number",
+                example: "[2.5 3.5 -2.5] | math round --mode half-even",
+                result: Some(Value::list(
+                    vec![Value::test_int(2), Value::test_int(4), Value::test_int(-2)],
+                    Span::test_data(),
+                )),
+            },
+            Example {
+                description: "Truncate toward zero",
+                example: "[2.7 -2.7] | math round --mode toward-zero",
+                result: Some(Value::list(
+                    vec![Value::test_int(2), Value::test_int(-2)],
+                    Span::test_data(),
+                )),
+            },
         ]
     }
 }
 
-fn operate(value: Value, head: Span, precision: Option<i64>) -> Value {
+fn operate(value: Value, head: Span, precision: Option<i64>, mode: RoundMode) -> Value {
     // We treat int values as float values in order to avoid code repetition in the match closure
     let span = value.span();
     let value = if let Value::Int { val, .. } = value {
@@ -109,11 +172,11 @@ fn operate(value: Value, head: Span, precision: Option<i64>) -> Value {
     match value {
         Value::Float { val, .. } => match precision {
             Some(precision_number) => Value::float(
-                (val * ((10_f64).powf(precision_number as f64))).round()
+                apply_mode(val * ((10_f64).powf(precision_number as f64)), mode)
                     / (10_f64).powf(precision_number as f64),
                 span,
             ),
-            None => Value::int(val.round() as i64, span),
+            None => Value::int(apply_mode(val, mode) as i64, span),
         },
         Value::Error { .. } => value,
         other => Value::error(
@@ -128,6 +191,26 @@ fn operate(value: Value, head: Span, precision: Option<i64>) -> Value {
     }
 }
 
+fn apply_mode(val: f64, mode: RoundMode) -> f64 {
+    match mode {
+        // `f64::round` already rounds ties away from zero
+        RoundMode::HalfUp => val.round(),
+        RoundMode::HalfEven => {
+            let rounded = val.round();
+            // an exact .5 fraction rounded to an odd number steps back toward
+            // zero to land on the even neighbor; everything else is unchanged
+            if (val - val.trunc()).abs() == 0.5 && rounded % 2.0 != 0.0 {
+                rounded - val.signum()
+            } else {
+                rounded
+            }
+        }
+        RoundMode::Floor => val.floor(),
+        RoundMode::Ceil => val.ceil(),
+        RoundMode::TowardZero => val.trunc(),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
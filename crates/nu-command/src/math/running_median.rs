@@ -0,0 +1,164 @@
+use super::median::median;
+use crate::math::utils::run_with_function;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math running-median"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math running-median")
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Number)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+                (
+                    Type::List(Box::new(Type::Duration)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+                (
+                    Type::List(Box::new(Type::Filesize)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+            ])
+            .required_named(
+                "window",
+                SyntaxShape::Int,
+                "the number of elements in each window",
+                Some('w'),
+            )
+            .switch(
+                "center",
+                "center the window on each element instead of ending it there",
+                Some('c'),
+            )
+            .switch(
+                "partial",
+                "compute the median of partial windows at the edges instead of returning nothing",
+                Some('p'),
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Returns the median of a sliding window ending at (or centered on) each element."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Elements without a full window produce nothing unless --partial is given."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["rolling", "sliding", "window", "smooth", "statistics"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let window: Spanned<i64> = call
+            .get_flag(engine_state, stack, "window")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "window".into(),
+                span: call.head,
+            })?;
+        if window.item < 1 {
+            return Err(ShellError::IncorrectValue {
+                msg: "window must be a positive integer".into(),
+                val_span: window.span,
+                call_span: call.head,
+            });
+        }
+        let window = window.item as usize;
+        let center = call.has_flag("center");
+        let partial = call.has_flag("partial");
+        run_with_function(call, input, move |values, span, head| {
+            running_median(values, span, head, window, center, partial)
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Compute the running median with a trailing window of 3",
+                example: "[1 5 2 8 3] | math running-median --window 3",
+                result: Some(Value::test_list(vec![
+                    Value::test_nothing(),
+                    Value::test_nothing(),
+                    Value::test_int(2),
+                    Value::test_int(5),
+                    Value::test_int(3),
+                ])),
+            },
+            Example {
+                description: "Fill the ramp-up with medians of partial windows",
+                example: "[1 5 2 8 3] | math running-median --window 3 --partial",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(1),
+                    Value::test_float(3.0),
+                    Value::test_int(2),
+                    Value::test_int(5),
+                    Value::test_int(3),
+                ])),
+            },
+        ]
+    }
+}
+
+fn running_median(
+    values: &[Value],
+    span: Span,
+    head: Span,
+    window: usize,
+    center: bool,
+    partial: bool,
+) -> Result<Value, ShellError> {
+    let mut medians = Vec::with_capacity(values.len());
+
+    for idx in 0..values.len() {
+        let (start, end) = if center {
+            let before = (window - 1) / 2;
+            let after = window - 1 - before;
+            (
+                idx.saturating_sub(before),
+                usize::min(idx + after + 1, values.len()),
+            )
+        } else {
+            (idx.saturating_sub(window - 1), idx + 1)
+        };
+
+        if end - start == window || partial {
+            medians.push(median(&values[start..end], span, head)?);
+        } else {
+            medians.push(Value::nothing(head));
+        }
+    }
+
+    Ok(Value::list(medians, head))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,120 @@
+use nu_cmd_base::input_handler::{operate, CellPathOnlyArgs};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::ast::CellPath;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math sign"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math sign")
+            .input_output_types(vec![
+                (Type::Number, Type::Int),
+                (Type::Duration, Type::Int),
+                (Type::Filesize, Type::Int),
+                (
+                    Type::List(Box::new(Type::Number)),
+                    Type::List(Box::new(Type::Int)),
+                ),
+                (Type::Table(vec![]), Type::Table(vec![])),
+                (Type::Record(vec![]), Type::Record(vec![])),
+            ])
+            .allow_variants_without_examples(true)
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, compute the sign at the given cell paths",
+            )
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Returns the sign of a number: -1 for negatives, 0 for zero, 1 for positives."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["signum", "positive", "negative"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let args = CellPathOnlyArgs::from(cell_paths);
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Compute the sign of each number in a list of numbers",
+                example: "[-50 0 25.5] | math sign",
+                result: Some(Value::list(
+                    vec![
+                        Value::test_int(-1),
+                        Value::test_int(0),
+                        Value::test_int(1),
+                    ],
+                    Span::test_data(),
+                )),
+            },
+            Example {
+                description: "Negative zero has a sign of zero",
+                example: "-0.0 | math sign",
+                result: Some(Value::test_int(0)),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, _arg: &CellPathOnlyArgs, head: Span) -> Value {
+    let span = input.span();
+    match input {
+        Value::Int { val, .. } | Value::Duration { val, .. } | Value::Filesize { val, .. } => {
+            Value::int(val.signum(), span)
+        }
+        // `f64::signum` maps `-0.0` to `-1.0`, so zero needs special treatment
+        Value::Float { val, .. } => {
+            if *val == 0.0 {
+                Value::int(0, span)
+            } else {
+                Value::int(val.signum() as i64, span)
+            }
+        }
+        Value::Error { .. } => input.clone(),
+        other => Value::error(
+            ShellError::OnlySupportsThisInputType {
+                exp_input_type: "numeric".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: head,
+                src_span: other.span(),
+            },
+            head,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,209 @@
+use super::outliers::coerce_float;
+use indexmap::map::IndexMap;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math weighted-avg"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math weighted-avg")
+            .input_output_types(vec![
+                (Type::List(Box::new(Type::Number)), Type::Number),
+                (Type::Table(vec![]), Type::Record(vec![])),
+            ])
+            .optional(
+                "weights",
+                SyntaxShape::List(Box::new(SyntaxShape::Number)),
+                "the weight of each input value, for list input",
+            )
+            .named(
+                "weights",
+                SyntaxShape::String,
+                "the column holding the weights, for table input",
+                Some('w'),
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Returns the weighted average of a list of numbers."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Weights must be non-negative and their sum must be greater than zero."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["average", "mean", "weight", "statistics"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let weights_arg: Option<Vec<Value>> = call.opt(engine_state, stack, 0)?;
+        let weights_column: Option<String> = call.get_flag(engine_state, stack, "weights")?;
+
+        let span = input.span().unwrap_or(head);
+        let values: Vec<Value> = input.into_iter().collect();
+
+        if matches!(values.first(), Some(Value::Record { .. })) {
+            let Some(column) = weights_column else {
+                return Err(ShellError::MissingParameter {
+                    param_name: "--weights <column>".into(),
+                    span: head,
+                });
+            };
+            weighted_average_of_table(&values, &column, span, head)
+                .map(|v| v.into_pipeline_data())
+        } else {
+            let Some(weights) = weights_arg else {
+                return Err(ShellError::MissingParameter {
+                    param_name: "weights".into(),
+                    span: head,
+                });
+            };
+            weighted_average(&values, &weights, span, head).map(|v| v.into_pipeline_data())
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Compute the weighted average of a list of numbers",
+                example: "[1 2 3] | math weighted-avg [1 1 2]",
+                result: Some(Value::test_float(2.25)),
+            },
+            Example {
+                description: "Compute the weighted average of a table column",
+                example: "[[value weight]; [1 1] [2 1] [3 2]] | math weighted-avg --weights weight",
+                result: Some(Value::test_record(record! {
+                    "value" => Value::test_float(2.25),
+                })),
+            },
+        ]
+    }
+}
+
+pub(super) fn weighted_average(
+    values: &[Value],
+    weights: &[Value],
+    span: Span,
+    head: Span,
+) -> Result<Value, ShellError> {
+    if values.len() != weights.len() {
+        return Err(ShellError::IncorrectValue {
+            msg: format!(
+                "expected {} weights to match the input, got {}",
+                values.len(),
+                weights.len()
+            ),
+            val_span: span,
+            call_span: head,
+        });
+    }
+
+    let mut total = 0.0;
+    let mut total_weight = 0.0;
+    for (value, weight) in values.iter().zip(weights) {
+        let w = coerce_float(weight, head)?;
+        if w < 0.0 {
+            return Err(ShellError::IncorrectValue {
+                msg: "weights must be non-negative".into(),
+                val_span: weight.span(),
+                call_span: head,
+            });
+        }
+        total += coerce_float(value, head)? * w;
+        total_weight += w;
+    }
+
+    if total_weight == 0.0 {
+        return Err(ShellError::IncorrectValue {
+            msg: "the total weight must be greater than zero".into(),
+            val_span: span,
+            call_span: head,
+        });
+    }
+
+    Ok(Value::float(total / total_weight, head))
+}
+
+fn weighted_average_of_table(
+    values: &[Value],
+    weights_column: &str,
+    span: Span,
+    head: Span,
+) -> Result<Value, ShellError> {
+    let mut weights = vec![];
+    let mut column_values = IndexMap::new();
+    for val in values {
+        match val {
+            Value::Record { val: record, .. } => {
+                let Some(weight) = record.get(weights_column) else {
+                    return Err(ShellError::CantFindColumn {
+                        col_name: weights_column.into(),
+                        span: val.span(),
+                        src_span: span,
+                    });
+                };
+                weights.push(weight.clone());
+                for (key, value) in record {
+                    if key != weights_column {
+                        column_values
+                            .entry(key.clone())
+                            .and_modify(|v: &mut Vec<Value>| v.push(value.clone()))
+                            .or_insert_with(|| vec![value.clone()]);
+                    }
+                }
+            }
+            Value::Error { error, .. } => return Err(*error.clone()),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "Only tables are supported with --weights".into(),
+                    "value originates from here".into(),
+                    head,
+                    other.span(),
+                ))
+            }
+        }
+    }
+
+    let mut column_totals = IndexMap::new();
+    for (col_name, col_vals) in column_values {
+        column_totals.insert(
+            col_name,
+            weighted_average(&col_vals, &weights, span, head)?,
+        );
+    }
+
+    Ok(Value::record(column_totals.into_iter().collect(), head))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,106 @@
+use super::avg::average;
+use super::outliers::coerce_float;
+use super::stddev::compute_stddev;
+use crate::math::utils::run_with_function;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math zscore"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math zscore")
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Number)),
+                    Type::List(Box::new(Type::Float)),
+                ),
+                (Type::Table(vec![]), Type::Record(vec![])),
+            ])
+            .switch(
+                "sample",
+                "calculate the z-scores using the sample standard deviation (i.e. using N-1 as the denominator)",
+                Some('s'),
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Returns the z-score of each element: its distance from the mean in standard deviations."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["standardize", "deviation", "statistics", "normal"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let sample = call.has_flag("sample");
+        run_with_function(call, input, move |values, span, head| {
+            zscore(values, span, head, sample)
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Standardize a list with mean 5 and standard deviation 2",
+            example: "[2 4 4 4 5 5 7 9] | math zscore",
+            result: Some(Value::test_list(vec![
+                Value::test_float(-1.5),
+                Value::test_float(-0.5),
+                Value::test_float(-0.5),
+                Value::test_float(-0.5),
+                Value::test_float(0.0),
+                Value::test_float(0.0),
+                Value::test_float(1.0),
+                Value::test_float(2.0),
+            ])),
+        }]
+    }
+}
+
+fn zscore(values: &[Value], span: Span, head: Span, sample: bool) -> Result<Value, ShellError> {
+    let mean = coerce_float(&average(values, span, head)?, head)?;
+    let stddev = coerce_float(&compute_stddev(sample)(values, span, head)?, head)?;
+
+    if stddev == 0.0 {
+        return Err(ShellError::IncorrectValue {
+            msg: "all elements are equal, so the standard deviation is zero".into(),
+            val_span: span,
+            call_span: head,
+        });
+    }
+
+    let scores = values
+        .iter()
+        .map(|value| Ok(Value::float((coerce_float(value, head)? - mean) / stddev, head)))
+        .collect::<Result<Vec<Value>, ShellError>>()?;
+
+    Ok(Value::list(scores, head))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
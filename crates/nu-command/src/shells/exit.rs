@@ -39,11 +39,12 @@ impl Command for Exit {
     ) -> Result<PipelineData, ShellError> {
         let exit_code: Option<i64> = call.opt(engine_state, stack, 0)?;
 
-        if let Some(exit_code) = exit_code {
-            std::process::exit(exit_code as i32);
-        }
-
-        std::process::exit(0);
+        // Unwind the evaluation instead of terminating in place, so streams
+        // and drop guards further up the stack are cleaned up properly.
+        Err(ShellError::Exit(
+            call.head,
+            exit_code.unwrap_or_default() as i32,
+        ))
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -31,6 +31,10 @@ impl Command for External {
         "run-external"
     }
 
+    fn is_destructive(&self) -> bool {
+        true
+    }
+
     fn usage(&self) -> &str {
         "Runs external command."
     }
@@ -119,3 +119,277 @@ fn find_in_table_keeps_row_with_multiple_matched_and_keeps_other_columns() {
     assert!(actual.out.contains("bill"));
     assert!(actual.out.contains("60"));
 }
+
+#[test]
+fn find_with_max_depth_zero_skips_nested_cells() {
+    let actual = nu!(
+        r#"[[name info]; [alpha {tag: special}] [beta plain]] | find special --max-depth 0 | length"#
+    );
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn find_with_max_depth_keeps_top_level_matches() {
+    let actual = nu!(
+        r#"[[name info]; [special {tag: alpha}] [beta plain]] | find special --max-depth 0 | get name | to json -r"#
+    );
+
+    assert_eq!(actual.out, r#"["special"]"#);
+}
+
+#[test]
+fn find_bool_term_matches_booleans() {
+    let actual = nu!("[true false true] | find true | length");
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn find_typed_term_does_not_match_rendered_lookalike() {
+    let actual = nu!(r#"["true" true] | find true | length"#);
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn find_query_filters_rows() {
+    let actual = nu!(
+        r#"[{status: error n: 1} {status: ok n: 2} {status: error n: 3}] | find --query '.status == "error"' | get n | to nuon"#
+    );
+    assert_eq!(actual.out, "[1, 3]");
+}
+
+#[test]
+fn find_query_spreads_over_lists() {
+    let actual = nu!(
+        r#"[{items: [{status: ok} {status: error}]} {items: [{status: ok}]}] | find --query '.items[].status == "error"' | length"#
+    );
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn find_query_and_or_existence() {
+    let actual = nu!(
+        r#"[{a: 1 b: 2} {a: 5} {a: 9 b: 0}] | find --query '.a > 2 and .b' | get a | to nuon"#
+    );
+    assert_eq!(actual.out, "[9]");
+}
+
+#[test]
+fn find_query_parse_error_mentions_expectation() {
+    let actual = nu!(r#"[{a: 1}] | find --query '.a =='"#);
+    assert!(actual.err.contains("expected a literal"));
+}
+
+#[test]
+fn find_summary_counts_each_term() {
+    let actual = nu!("[apple banana apricot] | find ap ban --summary | to nuon");
+    assert_eq!(actual.out, "{ap: 2, ban: 1}");
+}
+
+#[test]
+fn find_summary_with_invert_counts_misses() {
+    let actual = nu!("[apple banana apricot] | find ap --summary --invert | get ap");
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn find_col_ref_keeps_matching_rows() {
+    let actual = nu!(
+        "[[actual expected]; [1 1] [2 3] [4 4]] | find --col-ref {actual: expected} | get actual | to nuon"
+    );
+    assert_eq!(actual.out, "[1, 4]");
+}
+
+#[test]
+fn find_col_ref_invert_keeps_mismatches() {
+    let actual = nu!(
+        "[[actual expected]; [1 1] [2 3]] | find --col-ref {actual: expected} --invert | get actual | to nuon"
+    );
+    assert_eq!(actual.out, "[2]");
+}
+
+#[test]
+fn find_col_ref_missing_column_never_matches() {
+    let actual = nu!("[{a: 1}] | find --col-ref {a: b} | length");
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn find_default_mode_matches_line_by_line() {
+    let actual = nu!(r#""foo\nbar" | find bar | lines | length"#);
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn find_slurp_keeps_whole_input_together() {
+    let actual = nu!(r#""foo\nbar" | find bar --slurp | lines | length"#);
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn find_slurp_buffers_external_output() {
+    let actual = nu!(r#"nu --testbin cococo "foo\nbar" | find bar --slurp | ansi strip | lines | length"#);
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn find_before_context_merges_overlapping_windows() {
+    let actual = nu!("[1 9 9 1] | find 9 --before-context 1 | to nuon");
+    assert_eq!(actual.out, "[1, 9, 9]");
+}
+
+#[test]
+fn find_before_context_as_table_marks_matches() {
+    let actual = nu!("[a b] | find b --before-context 1 --as-table | get match | to nuon");
+    assert_eq!(actual.out, "[false, true]");
+}
+
+#[test]
+fn find_before_context_rejects_negative() {
+    let actual = nu!("[1 2] | find 2 --before-context -1");
+    assert!(actual.err.contains("cannot be negative"));
+}
+
+#[test]
+fn find_invert_keep_structure_masks_only_matches() {
+    let actual = nu!(r#"['a1b2' 'c'] | find --regex '\d' --invert-keep-structure --mask X | str join ','"#);
+    assert_eq!(actual.out, "aXbX,c");
+}
+
+#[test]
+fn find_invert_keep_structure_leaves_typed_cells_untouched() {
+    let actual = nu!(r#"[[n s]; [12 '12']] | find --regex '\d+' --invert-keep-structure | get 0 | to nuon"#);
+    assert_eq!(actual.out, r#"{n: 12, s: "****"}"#);
+}
+
+#[test]
+fn find_terms_from_list_flag() {
+    let actual = nu!("[moe larry curly] | find --terms [moe curly] | to json -r");
+
+    assert_eq!(actual.out, r#"["moe","curly"]"#);
+}
+
+#[test]
+fn find_terms_from_file_skips_comments_and_blanks() {
+    use nu_test_support::fs::Stub::FileWithContent;
+    use nu_test_support::playground::Playground;
+
+    Playground::setup("find_terms_file", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "terms.txt",
+            "# denylist\n\nmoe\ncurly\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "[moe larry curly] | find --terms-file terms.txt | to json -r"
+        );
+
+        assert_eq!(actual.out, r#"["moe","curly"]"#);
+    });
+}
+
+#[test]
+fn find_terms_file_missing_is_an_error() {
+    let actual = nu!("[moe] | find --terms-file does_not_exist.txt");
+
+    assert!(actual.err.contains("does_not_exist.txt"));
+}
+
+#[test]
+fn find_regex_file_patterns_are_alternatives() {
+    use nu_test_support::fs::Stub::FileWithContent;
+    use nu_test_support::playground::Playground;
+
+    Playground::setup("find_regex_file", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "patterns.txt",
+            "# patterns\n^moe$\nrl\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "[moe larry curly] | find --regex-file patterns.txt | to json -r"
+        );
+
+        assert_eq!(actual.out, r#"["moe","curly"]"#);
+    });
+}
+
+#[test]
+fn find_count_matches_sums_occurrences() {
+    let actual = nu!("'abba' | find --count-matches b");
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn find_count_matches_respects_columns() {
+    let actual = nu!("[[a b]; [xx xy]] | find x --columns [a] --count-matches");
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn find_count_matches_with_regex() {
+    let actual = nu!("[aXa Xa] | find --regex a --count-matches");
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn find_count_matches_is_case_insensitive() {
+    let actual = nu!("[Bob bab] | find b --count-matches");
+
+    assert_eq!(actual.out, "4");
+}
+
+#[test]
+fn find_highlight_color_overrides_theme() {
+    let actual = nu!("[moe larry] | find moe --highlight-color red | get 0");
+
+    assert!(actual.out.contains("\u{1b}[31m"));
+}
+
+#[test]
+fn find_highlight_uses_theme_without_flag() {
+    let actual = nu!("[moe larry] | find moe | get 0");
+
+    assert!(!actual.out.contains("\u{1b}[31m"));
+}
+
+#[test]
+fn find_inline_pattern_flags_override_command_switches() {
+    let actual = nu!(r#"[Exact exact] | find --regex '(?-i)Exact' --ignore-case | to json -r"#);
+
+    assert_eq!(actual.out, r#"["Exact"]"#);
+}
+
+#[test]
+fn find_inline_pattern_flags_work_without_switches() {
+    let actual = nu!(r#"[MOE moe] | find --regex '(?i)moe' | length"#);
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn find_regex_file_patterns_keep_their_own_flags() {
+    use nu_test_support::fs::Stub::FileWithContent;
+    use nu_test_support::playground::Playground;
+
+    Playground::setup("find_regex_file_flags", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "patterns.txt",
+            "(?-i)Moe\ncurly\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "[Moe moe CURLY] | find --regex-file patterns.txt --ignore-case | to json -r"
+        );
+
+        assert_eq!(actual.out, r#"["Moe","CURLY"]"#);
+    });
+}
@@ -288,3 +288,29 @@ fn redirection_should_have_a_target() {
         );
     }
 }
+
+#[cfg(not(windows))]
+#[test]
+fn redirect_err_to_pipe() {
+    Playground::setup("redirect_err_to_pipe_test", |dirs, _sandbox| {
+        let output = nu!(
+            cwd: dirs.test(),
+            "cat asdfasdfasdf.txt e>| str upcase"
+        );
+
+        assert!(output.out.contains("ASDFASDFASDF.TXT"));
+    })
+}
+
+#[cfg(not(windows))]
+#[test]
+fn redirect_outerr_to_pipe() {
+    Playground::setup("redirect_outerr_to_pipe_test", |dirs, _sandbox| {
+        let output = nu!(
+            cwd: dirs.test(),
+            r#"sh -c "echo out; echo err 1>&2" o+e>| lines | length"#
+        );
+
+        assert_eq!(output.out, "2");
+    })
+}
@@ -281,3 +281,199 @@ fn select_single_row_with_variable() {
     assert_eq!(actual.out, "[[a]; [3]]".to_string());
     assert!(actual.err.is_empty());
 }
+
+#[test]
+fn select_keeps_dots_in_literal_column_names() {
+    let actual = nu!(r#"{"a.b": 1} | select "a.b" | columns | get 0"#);
+    assert_eq!(actual.out, "a.b");
+
+    let actual = nu!(r#"let cols = ["a.b"]; {"a.b": 1} | select $cols | columns | get 0"#);
+    assert_eq!(actual.out, "a.b");
+}
+
+#[test]
+fn select_replaces_dots_for_nested_paths() {
+    let actual = nu!("{a: {b: 1}} | select a.b | columns | get 0");
+    assert_eq!(actual.out, "a_b");
+}
+
+#[test]
+fn select_rows_with_range() {
+    let actual = nu!("[[a]; [1] [2] [3] [4]] | select 1..2 | to nuon");
+    assert_eq!(actual.out, "[[a]; [2], [3]]");
+}
+
+#[test]
+fn select_rows_with_open_ended_range() {
+    let actual = nu!("[[a]; [1] [2] [3] [4]] | select 2.. | to nuon");
+    assert_eq!(actual.out, "[[a]; [3], [4]]");
+}
+
+#[test]
+fn select_rows_with_range_and_explicit_row() {
+    let actual = nu!("[[a]; [1] [2] [3] [4]] | select 0 2..3 | to nuon");
+    assert_eq!(actual.out, "[[a]; [1], [3], [4]]");
+}
+
+#[test]
+fn select_rows_with_reversed_range_errors() {
+    let actual = nu!("[[a]; [1] [2] [3]] | select 2..0");
+    assert!(actual.err.contains("ascending"));
+}
+
+#[test]
+fn select_with_default_fills_missing_cells() {
+    let actual = nu!(r#"[{a: 1} {a: 2 b: 3}] | select a b --default "N/A" | get b | to nuon"#);
+    assert_eq!(actual.out, r#"["N/A", 3]"#);
+}
+
+#[test]
+fn select_default_wins_over_ignore_errors() {
+    let actual = nu!("[{a: 1}] | select a b -i --default 0 | get 0.b");
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn select_reject_prefix_keeps_other_columns() {
+    let actual = nu!("[[a b c]; [1 2 3]] | select ^b | columns | str join ','");
+    assert_eq!(actual.out, "a,c");
+}
+
+#[test]
+fn select_reject_prefix_explicit_include_wins() {
+    let actual = nu!("[[a b c]; [1 2 3]] | select ^b b | columns | str join ','");
+    assert_eq!(actual.out, "a,b,c");
+}
+
+#[test]
+fn select_reject_prefix_on_record() {
+    let actual = nu!(r#"{a: 1 b: 2 c: 3} | select "^b" | to nuon"#);
+    assert_eq!(actual.out, "{a: 1, c: 3}");
+}
+
+#[test]
+fn select_depth_prunes_nested_records() {
+    let actual = nu!("{a: {b: {c: 1} d: 2} e: 3} | select a e --depth 1 | to nuon");
+    assert_eq!(actual.out, "{a: {b: null, d: 2}, e: 3}");
+}
+
+#[test]
+fn select_depth_zero_keeps_only_scalars() {
+    let actual = nu!("[{a: {b: 1} c: 2}] | select a c --depth 0 | to nuon");
+    assert_eq!(actual.out, "[[a, c]; [null, 2]]");
+}
+
+#[test]
+fn select_negative_depth_errors() {
+    let actual = nu!("{a: 1} | select a --depth -1");
+    assert!(actual.err.contains("negative"));
+}
+
+#[test]
+fn select_flattened_path_colliding_with_column_errors() {
+    let actual = nu!("{a: {b: 1} a_b: 2} | select a.b a_b");
+    assert!(actual.err.contains("field used twice"));
+}
+
+#[test]
+fn select_computed_name_colliding_with_column_errors() {
+    let actual = nu!("{a: 1} | select a {|r| $r.a } --as a");
+    assert!(actual.err.contains("field used twice"));
+}
+
+#[test]
+fn select_repeated_identical_column_is_deduplicated() {
+    let actual = nu!("{a: 1 b: 2} | select a a | to nuon");
+    assert_eq!(actual.out, "{a: 1}");
+}
+
+#[test]
+fn select_ignore_case_keeps_actual_casing() {
+    let actual = nu!("[[Name Size]; [foo 3]] | select name size --ignore-case | columns | str join ','");
+    assert_eq!(actual.out, "Name,Size");
+}
+
+#[test]
+fn select_ignore_case_ambiguous_columns_error() {
+    let actual = nu!("{a: 1 A: 2} | select a --ignore-case");
+    assert!(actual.err.contains("Ambiguous column name"));
+}
+
+#[test]
+fn select_list_with_non_column_value_has_help() {
+    let actual = nu!("[{a: 1}] | select [true]");
+    assert!(actual.err.contains("select columns must be strings or integers"));
+}
+
+#[test]
+fn select_values_unwraps_single_column() {
+    let actual = nu!("[{a: 1 b: x} {a: 2 b: y}] | select a --values | to nuon");
+
+    assert_eq!(actual.out, "[1, 2]");
+}
+
+#[test]
+fn select_values_on_record_returns_bare_value() {
+    let actual = nu!("{a: 1 b: 2} | select a --values");
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn select_values_honors_ignore_errors() {
+    let actual = nu!("[{a: 1} {b: 2}] | select a --values --ignore-errors | to nuon");
+
+    assert_eq!(actual.out, "[1, null]");
+}
+
+#[test]
+fn select_values_rejects_multiple_columns() {
+    let actual = nu!("[{a: 1 b: 2}] | select a b --values");
+
+    assert!(actual.err.contains("exactly one selected column"));
+}
+
+#[test]
+fn select_rename_closure_transforms_column_names() {
+    let actual = nu!(
+        "[[name size]; [a 1]] | select name size --rename {|col| $'col_($col)' } | get 0 | columns | to json -r"
+    );
+
+    assert_eq!(actual.out, r#"["col_name","col_size"]"#);
+}
+
+#[test]
+fn select_rename_collision_is_an_error() {
+    let actual = nu!("[[a b]; [1 2]] | select a b --rename {|col| 'same' }");
+
+    assert!(actual.err.contains("duplicate column names"));
+}
+
+#[test]
+fn select_rename_closure_error_names_the_column() {
+    let actual = nu!("[[a b]; [1 2]] | select a b --rename {|col| $col / 0 }");
+
+    assert!(actual.err.contains("renaming column 'a'"));
+}
+
+#[test]
+fn select_compact_drops_fully_null_rows() {
+    let actual = nu!("[{a: 1 b: 2} {c: 3}] | select a b --ignore-errors --compact | length");
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn select_compact_keeps_partially_null_rows() {
+    let actual =
+        nu!("[{a: 1 b: 2} {a: 3}] | select a b --ignore-errors --compact | get 1 | to json -r");
+
+    assert_eq!(actual.out, r#"{"a": 3,"b": null}"#);
+}
+
+#[test]
+fn select_compact_works_with_default() {
+    let actual = nu!("[{a: 1} {b: 2}] | select a --default null --compact | length");
+
+    assert_eq!(actual.out, "1");
+}
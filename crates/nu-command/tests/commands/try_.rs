@@ -81,3 +81,15 @@ fn catch_block_can_use_error_object() {
     let output = nu!("try {1 / 0} catch {|err| print ($err | get msg)}");
     assert_eq!(output.out, "Division by zero.")
 }
+
+#[test]
+fn catch_receives_external_exit_code() {
+    let output = nu!("try { nu --testbin fail } catch {|err| $err.exit_code }");
+    assert_eq!(output.out, "1");
+}
+
+#[test]
+fn catch_external_failure_has_structured_msg() {
+    let output = nu!("try { nu --testbin fail } catch {|err| $err.msg }");
+    assert_eq!(output.out, "External command failed");
+}
@@ -6,8 +6,10 @@ use nu_protocol::{
         Expression, Math, Operator, PathMember, PipelineElement, Redirection,
     },
     engine::{Closure, EngineState, Stack},
-    DeclId, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, Range, Record,
-    ShellError, Span, Spanned, Unit, Value, VarId, ENV_VARIABLE_ID,
+    Config, DataSource, DeclId, DidYouMean, IntoInterruptiblePipelineData, IntoPipelineData,
+    record, ListStream, PipelineData, PipelineMetadata, Range, RawStream, Record, ShellError,
+    Signature, Span, Spanned, Type, Unit, Value, VarId, ENV_VARIABLE_ID, INDEX_VARIABLE_ID,
+    IN_VARIABLE_ID,
 };
 use std::collections::HashMap;
 
@@ -15,13 +17,44 @@ pub fn eval_call(
     engine_state: &EngineState,
     caller_stack: &mut Stack,
     call: &Call,
-    input: PipelineData,
+    mut input: PipelineData,
 ) -> Result<PipelineData, ShellError> {
     if nu_utils::ctrl_c::was_pressed(&engine_state.ctrlc) {
+        // Embedders can opt into a distinct interrupt error instead of the
+        // default nothing value, which is indistinguishable from a command
+        // that legitimately returned nothing.
+        if engine_state.error_on_interrupt {
+            return Err(ShellError::InterruptedByUser {
+                span: Some(call.head),
+            });
+        }
         return Ok(Value::nothing(call.head).into_pipeline_data());
     }
     let decl = engine_state.get_decl(call.decl_id);
 
+    // In dry-run mode destructive commands are not executed; report what
+    // would have run instead.
+    if engine_state.dry_run && decl.is_destructive() {
+        let source = String::from_utf8_lossy(engine_state.get_span_contents(call.span()));
+        return Ok(
+            Value::string(format!("dry-run: would run `{}`", source.trim()), call.head)
+                .into_pipeline_data(),
+        );
+    }
+
+    // Commands that process binary data can opt out of the usual UTF-8 string
+    // conversion of external streams; their chunks arrive as Value::Binary
+    // verbatim. Commands that don't opt in are unaffected.
+    if decl.accepts_raw_binary_input() {
+        if let PipelineData::ExternalStream {
+            stdout: Some(ref mut stream),
+            ..
+        } = input
+        {
+            stream.is_binary = true;
+        }
+    }
+
     if !decl.is_known_external() && call.named_iter().any(|(flag, _, _)| flag.item == "help") {
         let mut signature = engine_state.get_signature(decl);
         signature.usage = decl.usage().to_string();
@@ -38,13 +71,23 @@ pub fn eval_call(
     } else if let Some(block_id) = decl.get_block_id() {
         let block = engine_state.get_block(block_id);
 
+        // One signature snapshot serves the whole call: `signature()` clones
+        // the declaration's signature, defaults included, so binding the
+        // parameter groups below reuses a single copy instead of cloning one
+        // each. The default values themselves are computed at parse time, so
+        // copying them per call is as cheap as it gets; memoizing them on the
+        // decl only becomes worthwhile if defaults ever turn into
+        // runtime-evaluated expressions, and then only for pure ones.
+        let signature = decl.signature();
+
         let mut callee_stack = caller_stack.gather_captures(engine_state, &block.captures);
 
-        for (param_idx, param) in decl
-            .signature()
+        let mut frame_args = vec![];
+
+        for (param_idx, param) in signature
             .required_positional
             .iter()
-            .chain(decl.signature().optional_positional.iter())
+            .chain(signature.optional_positional.iter())
             .enumerate()
         {
             let var_id = param
@@ -53,22 +96,36 @@ pub fn eval_call(
 
             if let Some(arg) = call.positional_nth(param_idx) {
                 let result = eval_expression(engine_state, caller_stack, arg)?;
+                frame_args.push(result.clone());
                 callee_stack.add_var(var_id, result);
             } else if let Some(value) = &param.default_value {
+                frame_args.push(value.to_owned());
                 callee_stack.add_var(var_id, value.to_owned());
             } else {
+                // A missing required positional binds `null` by default; with
+                // strict_positional_arguments set it errors instead. Optional
+                // positionals always bind `null`.
+                if engine_state.get_config().strict_positional_arguments
+                    && param_idx < signature.required_positional.len()
+                {
+                    return Err(ShellError::MissingParameter {
+                        param_name: param.name.clone(),
+                        span: call.head,
+                    });
+                }
                 callee_stack.add_var(var_id, Value::nothing(call.head));
             }
         }
 
-        if let Some(rest_positional) = decl.signature().rest_positional {
+        if let Some(rest_positional) = &signature.rest_positional {
             let mut rest_items = vec![];
 
-            for arg in call.positional_iter().skip(
-                decl.signature().required_positional.len()
-                    + decl.signature().optional_positional.len(),
-            ) {
+            for arg in call
+                .positional_iter()
+                .skip(signature.required_positional.len() + signature.optional_positional.len())
+            {
                 let result = eval_expression(engine_state, caller_stack, arg)?;
+                frame_args.push(result.clone());
                 rest_items.push(result);
             }
 
@@ -86,49 +143,72 @@ pub fn eval_call(
             )
         }
 
-        for named in decl.signature().named {
+        for named in &signature.named {
             if let Some(var_id) = named.var_id {
                 let mut found = false;
                 for call_named in call.named_iter() {
                     if let (Some(spanned), Some(short)) = (&call_named.1, named.short) {
                         if spanned.item == short.to_string() {
-                            if let Some(arg) = &call_named.2 {
-                                let result = eval_expression(engine_state, caller_stack, arg)?;
-
-                                callee_stack.add_var(var_id, result);
+                            let value = if let Some(arg) = &call_named.2 {
+                                eval_expression(engine_state, caller_stack, arg)?
                             } else if let Some(value) = &named.default_value {
-                                callee_stack.add_var(var_id, value.to_owned());
+                                value.to_owned()
                             } else {
-                                callee_stack.add_var(var_id, Value::bool(true, call.head))
-                            }
+                                Value::bool(true, call.head)
+                            };
+                            frame_args.push(value.clone());
+                            callee_stack.add_var(var_id, value);
                             found = true;
                         }
                     } else if call_named.0.item == named.long {
-                        if let Some(arg) = &call_named.2 {
-                            let result = eval_expression(engine_state, caller_stack, arg)?;
-
-                            callee_stack.add_var(var_id, result);
+                        let value = if let Some(arg) = &call_named.2 {
+                            eval_expression(engine_state, caller_stack, arg)?
                         } else if let Some(value) = &named.default_value {
-                            callee_stack.add_var(var_id, value.to_owned());
+                            value.to_owned()
                         } else {
-                            callee_stack.add_var(var_id, Value::bool(true, call.head))
-                        }
+                            Value::bool(true, call.head)
+                        };
+                        frame_args.push(value.clone());
+                        callee_stack.add_var(var_id, value);
                         found = true;
                     }
                 }
 
                 if !found {
-                    if named.arg.is_none() {
-                        callee_stack.add_var(var_id, Value::bool(false, call.head))
-                    } else if let Some(value) = named.default_value {
-                        callee_stack.add_var(var_id, value);
+                    let value = if named.arg.is_none() {
+                        Value::bool(false, call.head)
+                    } else if let Some(value) = &named.default_value {
+                        value.to_owned()
                     } else {
-                        callee_stack.add_var(var_id, Value::nothing(call.head))
-                    }
+                        Value::nothing(call.head)
+                    };
+                    frame_args.push(value.clone());
+                    callee_stack.add_var(var_id, value);
                 }
             }
         }
 
+        // Record this call's (decl, arguments) frame — positional, rest and
+        // named values alike — so that when the recursion limit trips,
+        // `detect_recursion_cycle` can tell a repeating, non-progressing
+        // frame apart from recursion that merely runs deep.
+        callee_stack.call_frames.push((call.decl_id, frame_args));
+
+        // If the body references `$in`, bind it to the incoming pipeline input
+        // up front, so `$in` reliably means the command's own input anywhere in
+        // the body — not just in the first pipeline, and never a stale `$in`
+        // captured from the caller (the explicit binding shadows any capture).
+        // The input has to be collected to be both bound and passed on as the
+        // block's pipeline input; collection goes through `into_value`, which
+        // stays interruptible for streams carrying a ctrl-c signal. Bodies that
+        // don't mention `$in` keep streaming untouched.
+        if block.captures.contains(&IN_VARIABLE_ID) {
+            let metadata = input.metadata();
+            let value = input.into_value(call.head);
+            callee_stack.add_var(IN_VARIABLE_ID, value.clone());
+            input = PipelineData::Value(value, metadata);
+        }
+
         let result = eval_block_with_early_return(
             engine_state,
             &mut callee_stack,
@@ -144,13 +224,95 @@ pub fn eval_call(
 
         result
     } else {
+        if decl.coerces_input() {
+            input = coerce_input_to_signature(&decl.signature(), input, call.head)?;
+        }
+
         // We pass caller_stack here with the knowledge that internal commands
         // are going to be specifically looking for global state in the stack
         // rather than any local state.
         decl.run(engine_state, caller_stack, call, input)
+            // Stamp provenance onto output with no metadata, so downstream
+            // consumers can name the producing command; metadata the command
+            // attached itself (e.g. ls's data source) is more specific and
+            // kept as-is.
+            .map(|data| {
+                if data.metadata().is_none() {
+                    data.set_metadata(Some(PipelineMetadata {
+                        data_source: DataSource::Command {
+                            name: decl.name().to_string(),
+                            span: call.head,
+                        },
+                    }))
+                } else {
+                    data
+                }
+            })
+            .map_err(|err| err.wrap_with_command_context(decl.name(), call.head))
     }
 }
 
+/// Coerce pipeline input to a declared input type of `signature`, for
+/// commands opting in via `Command::coerces_input`.
+///
+/// Input already matching one of the declared input types passes through
+/// unchanged, as do streams (collecting them here would break interactivity)
+/// and signatures declaring no input types. Otherwise the only conversions
+/// attempted are lossless ones: an int where a float or number is declared,
+/// and an int, float or bool where a string is declared. Anything else is a
+/// type mismatch reported against the first declared input type.
+fn coerce_input_to_signature(
+    signature: &Signature,
+    input: PipelineData,
+    head: Span,
+) -> Result<PipelineData, ShellError> {
+    let declared: Vec<&Type> = signature
+        .input_output_types
+        .iter()
+        .map(|(input_type, _)| input_type)
+        .collect();
+
+    if declared.is_empty() {
+        return Ok(input);
+    }
+
+    let PipelineData::Value(value, metadata) = input else {
+        return Ok(input);
+    };
+
+    let actual = value.get_type();
+    if declared.iter().any(|decl| actual.is_subtype(decl)) {
+        return Ok(PipelineData::Value(value, metadata));
+    }
+
+    let span = value.span();
+    for decl in &declared {
+        let coerced = match (decl, &value) {
+            (Type::Float | Type::Number, Value::Int { val, .. }) => {
+                Some(Value::float(*val as f64, span))
+            }
+            (Type::String, Value::Int { val, .. }) => Some(Value::string(val.to_string(), span)),
+            (Type::String, Value::Float { val, .. }) => Some(Value::string(val.to_string(), span)),
+            (Type::String, Value::Bool { val, .. }) => Some(Value::string(val.to_string(), span)),
+            _ => None,
+        };
+        if let Some(coerced) = coerced {
+            return Ok(PipelineData::Value(coerced, metadata));
+        }
+    }
+
+    Err(ShellError::OnlySupportsThisInputType {
+        exp_input_type: declared
+            .iter()
+            .map(|decl| decl.to_string())
+            .collect::<Vec<String>>()
+            .join(", "),
+        wrong_type: actual.to_string(),
+        dst_span: head,
+        src_span: span,
+    })
+}
+
 /// Redirect the environment from callee to the caller.
 pub fn redirect_env(engine_state: &EngineState, caller_stack: &mut Stack, callee_stack: &Stack) {
     // Grab all environment variables from the callee
@@ -186,9 +348,22 @@ fn eval_external(
     is_subexpression: bool,
 ) -> Result<PipelineData, ShellError> {
     let decl_id = engine_state
-        .find_decl("run-external".as_bytes(), &[])
+        .find_run_external_decl()
         .ok_or(ShellError::ExternalNotSupported { span: head.span })?;
 
+    // Externals bypass eval_call, so dry-run mode has to intercept them here.
+    if engine_state.dry_run {
+        let span = Span::new(
+            head.span.start,
+            args.last().map(|arg| arg.span.end).unwrap_or(head.span.end),
+        );
+        let source = String::from_utf8_lossy(engine_state.get_span_contents(span));
+        return Ok(
+            Value::string(format!("dry-run: would run `{}`", source.trim()), head.span)
+                .into_pipeline_data(),
+        );
+    }
+
     let command = engine_state.get_decl(decl_id);
 
     let mut call = Call::new(head.span);
@@ -247,6 +422,264 @@ fn eval_external(
     command.run(engine_state, stack, &call, input)
 }
 
+/// A cache for the results of pure expressions, keyed by the expression's span.
+///
+/// Evaluation is opt-in: callers that repeatedly evaluate the same block (loops,
+/// `each`-style commands, ...) can thread one of these through
+/// [`eval_expression_with_cache`] to avoid recomputing expressions that
+/// [`expression_is_pure`] deems safe to memoize. Expressions that are not pure
+/// are evaluated normally and never stored.
+#[derive(Debug, Default)]
+pub struct ExpressionCache {
+    results: HashMap<Span, Value>,
+}
+
+impl ExpressionCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Checks whether an expression is pure, i.e. whether evaluating it can neither
+/// observe nor cause side effects, so its result only depends on the AST itself.
+///
+/// This is deliberately conservative: only literals and operator combinations of
+/// literals are considered pure. Variable reads (even immutable ones), calls,
+/// external calls, subexpressions, and filesystem-dependent expressions like
+/// `Expr::Filepath` all report `false`.
+pub fn expression_is_pure(expr: &Expression) -> bool {
+    match &expr.expr {
+        Expr::Bool(_)
+        | Expr::Int(_)
+        | Expr::Float(_)
+        | Expr::Binary(_)
+        | Expr::String(_)
+        | Expr::DateTime(_)
+        | Expr::CellPath(_)
+        | Expr::Nothing => true,
+        Expr::ValueWithUnit(e, _) => expression_is_pure(e),
+        Expr::Range(from, next, to, _) => [from, next, to]
+            .into_iter()
+            .all(|e| e.as_ref().map_or(true, |e| expression_is_pure(e))),
+        Expr::UnaryNot(e) => expression_is_pure(e),
+        Expr::BinaryOp(lhs, op, rhs) => {
+            // Assignments mutate their target and custom operators run a
+            // command, so neither is pure.
+            !matches!(
+                &op.expr,
+                Expr::Operator(Operator::Assignment(_) | Operator::Custom(_))
+            )
+                && expression_is_pure(lhs)
+                && expression_is_pure(op)
+                && expression_is_pure(rhs)
+        }
+        Expr::Operator(_) => true,
+        Expr::List(exprs) => exprs.iter().all(expression_is_pure),
+        Expr::Record(fields) => fields
+            .iter()
+            .all(|(col, val)| expression_is_pure(col) && expression_is_pure(val)),
+        Expr::Table(headers, rows) => {
+            headers.iter().all(expression_is_pure)
+                && rows.iter().all(|row| row.iter().all(expression_is_pure))
+        }
+        Expr::Keyword(_, _, e) => expression_is_pure(e),
+        Expr::StringInterpolation(exprs) => exprs.iter().all(expression_is_pure),
+        _ => false,
+    }
+}
+
+/// Evaluates an expression like [`eval_expression`], but refuses to evaluate
+/// anything with side effects: calls, external commands, assignments,
+/// subexpressions and the like (see [`expression_is_pure`]). Hosts can use this
+/// to safely evaluate literal-ish expressions — records, lists, arithmetic —
+/// from untrusted input, e.g. for config defaults or completion previews.
+pub fn eval_expression_pure(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    expr: &Expression,
+) -> Result<Value, ShellError> {
+    if let Some(impure) = find_impure_expression(expr) {
+        return Err(ShellError::NotAConstant(impure.span));
+    }
+
+    eval_expression(engine_state, stack, expr)
+}
+
+/// The first side-effecting (sub)expression within `expr`, if any, so the
+/// [`eval_expression_pure`] error can point at the precise culprit.
+fn find_impure_expression(expr: &Expression) -> Option<&Expression> {
+    if expression_is_pure(expr) {
+        return None;
+    }
+    match &expr.expr {
+        Expr::ValueWithUnit(e, _) | Expr::UnaryNot(e) | Expr::Keyword(_, _, e) => {
+            find_impure_expression(e)
+        }
+        Expr::Range(from, next, to, _) => [from, next, to]
+            .into_iter()
+            .filter_map(|e| e.as_ref())
+            .find_map(find_impure_expression),
+        Expr::BinaryOp(lhs, op, rhs) => {
+            if matches!(
+                &op.expr,
+                Expr::Operator(Operator::Assignment(_) | Operator::Custom(_))
+            ) {
+                Some(expr)
+            } else {
+                [lhs.as_ref(), op.as_ref(), rhs.as_ref()]
+                    .into_iter()
+                    .find_map(find_impure_expression)
+            }
+        }
+        Expr::List(exprs) => exprs.iter().find_map(find_impure_expression),
+        Expr::Record(fields) => fields.iter().find_map(|(col, val)| {
+            find_impure_expression(col).or_else(|| find_impure_expression(val))
+        }),
+        Expr::Table(headers, rows) => headers
+            .iter()
+            .find_map(find_impure_expression)
+            .or_else(|| rows.iter().flatten().find_map(find_impure_expression)),
+        Expr::StringInterpolation(exprs) => exprs.iter().find_map(find_impure_expression),
+        // The expression itself is the impure construct (a call, external
+        // call, variable, subexpression, ...).
+        _ => Some(expr),
+    }
+}
+
+/// Whether an expression references the pipeline input variable `$in`,
+/// directly or through a subexpression, block or closure capturing it.
+/// Condition-taking commands (`if`, ...) use this to decide whether their
+/// pipeline input has to be collected and bound to `$in` before the condition
+/// expression is evaluated, or can flow to the chosen branch untouched.
+pub fn expression_references_in(engine_state: &EngineState, expr: &Expression) -> bool {
+    match &expr.expr {
+        Expr::Var(var_id) => *var_id == IN_VARIABLE_ID,
+        Expr::ValueWithUnit(e, _) | Expr::UnaryNot(e) | Expr::Keyword(_, _, e) => {
+            expression_references_in(engine_state, e)
+        }
+        Expr::Range(from, next, to, _) => [from, next, to]
+            .into_iter()
+            .filter_map(|e| e.as_ref())
+            .any(|e| expression_references_in(engine_state, e)),
+        Expr::BinaryOp(lhs, op, rhs) => [lhs.as_ref(), op.as_ref(), rhs.as_ref()]
+            .into_iter()
+            .any(|e| expression_references_in(engine_state, e)),
+        Expr::List(exprs) | Expr::StringInterpolation(exprs) => exprs
+            .iter()
+            .any(|e| expression_references_in(engine_state, e)),
+        Expr::Record(fields) => fields.iter().any(|(col, val)| {
+            expression_references_in(engine_state, col)
+                || expression_references_in(engine_state, val)
+        }),
+        Expr::Table(headers, rows) => {
+            headers
+                .iter()
+                .any(|e| expression_references_in(engine_state, e))
+                || rows
+                    .iter()
+                    .flatten()
+                    .any(|e| expression_references_in(engine_state, e))
+        }
+        Expr::FullCellPath(cell_path) => expression_references_in(engine_state, &cell_path.head),
+        Expr::Call(call) => call.arguments.iter().any(|arg| match arg {
+            Argument::Positional(e) | Argument::Unknown(e) => {
+                expression_references_in(engine_state, e)
+            }
+            Argument::Named((_, _, Some(e))) => expression_references_in(engine_state, e),
+            Argument::Named((_, _, None)) => false,
+        }),
+        Expr::Subexpression(block_id)
+        | Expr::Block(block_id)
+        | Expr::Closure(block_id)
+        | Expr::RowCondition(block_id) => engine_state
+            .get_block(*block_id)
+            .captures
+            .contains(&IN_VARIABLE_ID),
+        _ => false,
+    }
+}
+
+/// Evaluate the const-eligible subset of expressions (literals, arithmetic,
+/// const-callable commands) against a merged engine state, without a stack.
+/// This is the engine-side entry point to the const evaluator that the parser
+/// uses for `const` declarations; embedders can call it to populate constants
+/// at merge time. Disallowed expressions return [`ShellError::NotAConstant`],
+/// matching `const` semantics.
+pub fn eval_constant(engine_state: &EngineState, expr: &Expression) -> Result<Value, ShellError> {
+    let working_set = nu_protocol::engine::StateWorkingSet::new(engine_state);
+    nu_protocol::eval_const::eval_constant(&working_set, expr)
+}
+
+/// Evaluates an expression like [`eval_expression`], but memoizes the results of
+/// pure expressions (see [`expression_is_pure`]) in the given cache.
+pub fn eval_expression_with_cache(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    expr: &Expression,
+    cache: &mut ExpressionCache,
+) -> Result<Value, ShellError> {
+    if !expression_is_pure(expr) {
+        return eval_expression(engine_state, stack, expr);
+    }
+
+    if let Some(value) = cache.results.get(&expr.span) {
+        return Ok(value.clone());
+    }
+
+    let value = eval_expression(engine_state, stack, expr)?;
+    cache.results.insert(expr.span, value.clone());
+    Ok(value)
+}
+
+/// Whether a datetime literal's source text spells out an offset: a trailing
+/// `Z`/`z` or a `+`/`-` offset after the time part. Date-only and naive
+/// date-time literals do not.
+fn datetime_span_has_offset(engine_state: &EngineState, span: Span) -> bool {
+    let contents = engine_state.get_span_contents(span);
+    match contents.iter().position(|b| *b == b'T') {
+        Some(idx) => {
+            let time_part = &contents[idx + 1..];
+            time_part.contains(&b'Z')
+                || time_part.contains(&b'z')
+                || time_part.contains(&b'+')
+                || time_part.contains(&b'-')
+        }
+        None => false,
+    }
+}
+
+/// Reattach a naive datetime literal to the timezone configured as
+/// `datetime_literal_timezone`: either a fixed offset like `+02:00` or the
+/// string `local`.
+fn normalize_datetime_literal(
+    dt: chrono::DateTime<chrono::FixedOffset>,
+    timezone: &str,
+    span: Span,
+) -> Result<chrono::DateTime<chrono::FixedOffset>, ShellError> {
+    use chrono::TimeZone;
+
+    let offset = if timezone == "local" {
+        *chrono::Local::now().offset()
+    } else {
+        timezone
+            .parse::<chrono::FixedOffset>()
+            .map_err(|_| ShellError::IncorrectValue {
+                msg: format!("invalid datetime_literal_timezone '{timezone}' in config"),
+                val_span: span,
+                call_span: span,
+            })?
+    };
+
+    offset
+        .from_local_datetime(&dt.naive_local())
+        .single()
+        .ok_or_else(|| ShellError::IncorrectValue {
+            msg: "datetime literal is ambiguous in the configured timezone".into(),
+            val_span: span,
+            call_span: span,
+        })
+}
+
 pub fn eval_expression(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -263,7 +696,7 @@ pub fn eval_expression(
                 to_type: "unit value".into(),
                 from_type: x.get_type().to_string(),
                 span: e.span,
-                help: None,
+                help: Some("the number before a unit must be an integer".into()),
             }),
         },
         Expr::Range(from, next, to, operator) => {
@@ -323,7 +756,21 @@ pub fn eval_expression(
             )?
             .into_value(span))
         }
-        Expr::DateTime(dt) => Ok(Value::date(*dt, expr.span)),
+        Expr::DateTime(dt) => {
+            // Opt-in: naive literals (written without an explicit offset) can
+            // be normalized to a configured timezone. Literals that spell out
+            // an offset, and conversions via `into datetime --timezone`, keep
+            // their offset either way.
+            let timezone = &engine_state.get_config().datetime_literal_timezone;
+            if timezone.is_empty() || datetime_span_has_offset(engine_state, expr.span) {
+                Ok(Value::date(*dt, expr.span))
+            } else {
+                Ok(Value::date(
+                    normalize_datetime_literal(*dt, timezone, expr.span)?,
+                    expr.span,
+                ))
+            }
+        }
         Expr::Operator(_) => Ok(Value::nothing(expr.span)),
         Expr::MatchPattern(pattern) => Ok(Value::match_pattern(*pattern.clone(), expr.span)),
         Expr::MatchBlock(_) => Ok(Value::nothing(expr.span)), // match blocks are handled by `match`
@@ -331,9 +778,12 @@ pub fn eval_expression(
             let lhs = eval_expression(engine_state, stack, expr)?;
             match lhs {
                 Value::Bool { val, .. } => Ok(Value::bool(!val, expr.span)),
-                other => Err(ShellError::TypeMismatch {
-                    err_message: format!("expected bool, found {}", other.get_type()),
+                other => Err(ShellError::OperatorUnsupportedType {
+                    operator: "not".into(),
+                    expected: Type::Bool,
+                    found: other.get_type(),
                     span: expr.span,
+                    help: "convert the value with `into bool`, or produce a boolean with a comparison such as `== null`".into(),
                 }),
             }
         }
@@ -383,6 +833,23 @@ pub fn eval_expression(
                     }
                 }
                 Operator::Comparison(comparison) => {
+                    // `0 < $x < 10` reads as `0 < $x and $x < 10`, with the
+                    // shared operand evaluated once.
+                    if is_ordering_comparison(&comparison)
+                        && as_comparison_chain_link(lhs).is_some()
+                    {
+                        let (result, _) = eval_comparison_chain(
+                            engine_state,
+                            stack,
+                            lhs,
+                            &comparison,
+                            rhs,
+                            op_span,
+                            expr.span,
+                        )?;
+                        return Ok(Value::bool(result, expr.span));
+                    }
+
                     let lhs = eval_expression(engine_state, stack, lhs)?;
                     let rhs = eval_expression(engine_state, stack, rhs)?;
                     match comparison {
@@ -390,8 +857,24 @@ pub fn eval_expression(
                         Comparison::LessThanOrEqual => lhs.lte(op_span, &rhs, expr.span),
                         Comparison::GreaterThan => lhs.gt(op_span, &rhs, expr.span),
                         Comparison::GreaterThanOrEqual => lhs.gte(op_span, &rhs, expr.span),
-                        Comparison::Equal => lhs.eq(op_span, &rhs, expr.span),
-                        Comparison::NotEqual => lhs.ne(op_span, &rhs, expr.span),
+                        Comparison::Equal => {
+                            // a non-zero epsilon makes float equality tolerant
+                            // to rounding error, e.g. `0.1 + 0.2 == 0.3`
+                            let epsilon = engine_state.get_config().float_equality_epsilon;
+                            if epsilon > 0.0 {
+                                lhs.approx_eq(op_span, &rhs, expr.span, epsilon)
+                            } else {
+                                lhs.eq(op_span, &rhs, expr.span)
+                            }
+                        }
+                        Comparison::NotEqual => {
+                            let epsilon = engine_state.get_config().float_equality_epsilon;
+                            if epsilon > 0.0 {
+                                lhs.approx_ne(op_span, &rhs, expr.span, epsilon)
+                            } else {
+                                lhs.ne(op_span, &rhs, expr.span)
+                            }
+                        }
                         Comparison::In => lhs.r#in(op_span, &rhs, expr.span),
                         Comparison::NotIn => lhs.not_in(op_span, &rhs, expr.span),
                         Comparison::RegexMatch => {
@@ -415,6 +898,20 @@ pub fn eval_expression(
                         Bits::ShiftRight => lhs.bit_shr(op_span, &rhs, expr.span),
                     }
                 }
+                Operator::Custom(decl_id) => {
+                    // The parser resolved `$a <name> $b` to an operator decl;
+                    // dispatch is an ordinary call with the unevaluated
+                    // operands as the two positionals, so the decl controls
+                    // their evaluation like any other command.
+                    let mut dispatch = Call::new(op_span);
+                    dispatch.decl_id = decl_id;
+                    dispatch.add_positional(*lhs.clone());
+                    dispatch.add_positional(*rhs.clone());
+                    Ok(
+                        eval_call(engine_state, stack, &dispatch, PipelineData::empty())?
+                            .into_value(expr.span),
+                    )
+                }
                 Operator::Assignment(assignment) => {
                     let rhs = eval_expression(engine_state, stack, rhs)?;
 
@@ -462,7 +959,6 @@ pub fn eval_expression(
                                         let mut lhs =
                                             eval_expression(engine_state, stack, &cell_path.head)?;
 
-                                        lhs.upsert_data_at_cell_path(&cell_path.tail, rhs)?;
                                         if is_env {
                                             if cell_path.tail.is_empty() {
                                                 return Err(ShellError::CannotReplaceEnv {
@@ -470,6 +966,31 @@ pub fn eval_expression(
                                                 });
                                             }
 
+                                            // Automatic variables are off-limits no matter how
+                                            // deep the assignment reaches ($env.PWD.foo = ..
+                                            // would rewrite PWD just like $env.PWD = ..);
+                                            // checking before the mutation keeps the error
+                                            // about the variable rather than about indexing
+                                            // into its value.
+                                            if let Some(PathMember::String { val, span, .. }) =
+                                                cell_path.tail.first()
+                                            {
+                                                if val == "FILE_PWD"
+                                                    || val == "CURRENT_FILE"
+                                                    || val == "PWD"
+                                                {
+                                                    return Err(
+                                                        ShellError::AutomaticEnvVarSetManually {
+                                                            envvar_name: val.to_string(),
+                                                            span: *span,
+                                                        },
+                                                    );
+                                                }
+                                            }
+                                        }
+
+                                        lhs.upsert_data_at_cell_path(&cell_path.tail, rhs)?;
+                                        if is_env {
                                             // The special $env treatment: for something like $env.config.history.max_size = 2000,
                                             // get $env.config (or whichever one it is) AFTER the above mutation, and set it
                                             // as the "config" environment variable.
@@ -478,18 +999,8 @@ pub fn eval_expression(
                                                 false,
                                             )?;
                                             match &cell_path.tail[0] {
-                                                PathMember::String { val, span, .. } => {
-                                                    if val == "FILE_PWD"
-                                                        || val == "CURRENT_FILE"
-                                                        || val == "PWD"
-                                                    {
-                                                        return Err(ShellError::AutomaticEnvVarSetManually {
-                                                    envvar_name: val.to_string(),
-                                                    span: *span,
-                                                });
-                                                    } else {
-                                                        stack.add_env_var(val.to_string(), vardata);
-                                                    }
+                                                PathMember::String { val, .. } => {
+                                                    stack.add_env_var(val.to_string(), vardata);
                                                 }
                                                 // In case someone really wants an integer env-var
                                                 PathMember::Int { val, .. } => {
@@ -528,6 +1039,22 @@ pub fn eval_expression(
             let block = engine_state.get_block(*block_id);
 
             for var_id in &block.captures {
+                // `$env` is deliberately not snapshotted at closure creation:
+                // closures read the environment live from the stack they run
+                // on, so env mutations made between creation and invocation
+                // stay visible, matching `$env.FOO = ..` everywhere else.
+                if *var_id == ENV_VARIABLE_ID {
+                    continue;
+                }
+                // `$index` is only bound while an iterating command runs the
+                // closure; a closure merely created outside an iteration
+                // context has no value to capture for it yet.
+                if *var_id == INDEX_VARIABLE_ID {
+                    if let Ok(index) = stack.get_var(*var_id, expr.span) {
+                        captures.insert(*var_id, index);
+                    }
+                    continue;
+                }
                 captures.insert(*var_id, stack.get_var(*var_id, expr.span)?);
             }
             Ok(Value::closure(
@@ -540,6 +1067,7 @@ pub fn eval_expression(
         }
         Expr::Block(block_id) => Ok(Value::block(*block_id, expr.span)),
         Expr::List(x) => {
+            check_collection_size(engine_state, x.len(), "list literal", expr.span)?;
             let mut output = vec![];
             for expr in x {
                 output.push(eval_expression(engine_state, stack, expr)?);
@@ -547,6 +1075,7 @@ pub fn eval_expression(
             Ok(Value::list(output, expr.span))
         }
         Expr::Record(fields) => {
+            check_collection_size(engine_state, fields.len(), "record literal", expr.span)?;
             let mut record = Record::new();
 
             for (col, val) in fields {
@@ -569,6 +1098,12 @@ pub fn eval_expression(
             Ok(Value::record(record, expr.span))
         }
         Expr::Table(headers, vals) => {
+            check_collection_size(
+                engine_state,
+                vals.len().saturating_mul(headers.len()),
+                "table literal",
+                expr.span,
+            )?;
             let mut output_headers = vec![];
             for expr in headers {
                 output_headers.push(eval_expression(engine_state, stack, expr)?.as_string()?);
@@ -592,18 +1127,36 @@ pub fn eval_expression(
         }
         Expr::Keyword(_, _, expr) => eval_expression(engine_state, stack, expr),
         Expr::StringInterpolation(exprs) => {
+            let config = engine_state.get_config().clone();
+
             let mut parts = vec![];
-            for expr in exprs {
-                parts.push(eval_expression(engine_state, stack, expr)?);
+            let mut exprs_iter = exprs.iter().peekable();
+            while let Some(part) = exprs_iter.next() {
+                // A format-spec part formats the value of the part that follows it;
+                // a trailing spec with nothing to format is rendered literally.
+                if let (Expr::String(spec), Type::Custom(marker)) = (&part.expr, &part.ty) {
+                    if marker == "format-spec" {
+                        if let Some(value_part) = exprs_iter.next() {
+                            let value = eval_expression(engine_state, stack, value_part)?;
+                            parts.push(Value::string(
+                                format_value_with_spec(&value, spec, &config, value_part.span)?,
+                                part.span,
+                            ));
+                            continue;
+                        }
+                    }
+                }
+                parts.push(eval_expression(engine_state, stack, part)?);
             }
 
-            let config = engine_state.get_config();
-
-            parts
+            let string = parts
                 .into_iter()
                 .into_pipeline_data(None)
-                .collect_string("", config)
-                .map(|x| Value::string(x, expr.span))
+                .collect_string("", &config)?;
+            // interpolations grow with the rendered values, so the cap is
+            // checked against the assembled string, in bytes
+            check_collection_size(engine_state, string.len(), "string interpolation", expr.span)?;
+            Ok(Value::string(string, expr.span))
         }
         Expr::String(s) => Ok(Value::string(s.clone(), expr.span)),
         Expr::Filepath(s) => {
@@ -640,6 +1193,157 @@ pub fn eval_expression(
 ///
 /// It returns PipelineData with a boolean flag, indicating if the external failed to run.
 /// The boolean flag **may only be true** for external calls, for internal calls, it always to be false.
+/// The ordering operators that participate in chained comparisons. Equality,
+/// membership and regex comparisons keep their binary meaning.
+fn is_ordering_comparison(comparison: &Comparison) -> bool {
+    matches!(
+        comparison,
+        Comparison::LessThan
+            | Comparison::LessThanOrEqual
+            | Comparison::GreaterThan
+            | Comparison::GreaterThanOrEqual
+    )
+}
+
+/// If an expression is itself an ordering comparison, return its pieces so it
+/// can act as the left link of a chain.
+fn as_comparison_chain_link(
+    expr: &Expression,
+) -> Option<(&Expression, &Comparison, &Expression, Span)> {
+    if let Expr::BinaryOp(lhs, op, rhs) = &expr.expr {
+        if let Expr::Operator(Operator::Comparison(comparison)) = &op.expr {
+            if is_ordering_comparison(comparison) {
+                return Some((lhs, comparison, rhs, op.span));
+            }
+        }
+    }
+    None
+}
+
+/// Evaluate a chained ordering comparison left to right. Chains associate to
+/// the left and may mix `<`, `<=`, `>` and `>=`; the chain holds when every
+/// adjacent pair holds. Returns the truth value so far plus the rightmost
+/// operand, so shared operands are evaluated exactly once, and short-circuits:
+/// after a false link, operands further right are not evaluated.
+fn eval_comparison_chain(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    lhs: &Expression,
+    comparison: &Comparison,
+    rhs: &Expression,
+    op_span: Span,
+    span: Span,
+) -> Result<(bool, Option<Value>), ShellError> {
+    let (left_ok, left_val) = match as_comparison_chain_link(lhs) {
+        Some((llhs, lcomparison, lrhs, lop_span)) => eval_comparison_chain(
+            engine_state,
+            stack,
+            llhs,
+            lcomparison,
+            lrhs,
+            lop_span,
+            lhs.span,
+        )?,
+        None => (true, Some(eval_expression(engine_state, stack, lhs)?)),
+    };
+    if !left_ok {
+        return Ok((false, None));
+    }
+    let left_val = left_val.expect("a true chain link carries its right operand");
+
+    let right_val = eval_expression(engine_state, stack, rhs)?;
+    let result = match comparison {
+        Comparison::LessThan => left_val.lt(op_span, &right_val, span)?,
+        Comparison::LessThanOrEqual => left_val.lte(op_span, &right_val, span)?,
+        Comparison::GreaterThan => left_val.gt(op_span, &right_val, span)?,
+        Comparison::GreaterThanOrEqual => left_val.gte(op_span, &right_val, span)?,
+        _ => unreachable!("only ordering comparisons form chain links"),
+    };
+
+    Ok((result.is_true(), Some(right_val)))
+}
+
+/// Count how many times an expression refers to `$in`, mirroring
+/// [`Expression::has_in_variable`] but resolving blocks through the engine
+/// state since the working set is gone by evaluation time.
+fn count_in_variable_refs(engine_state: &EngineState, expr: &Expression) -> usize {
+    match &expr.expr {
+        Expr::Var(var_id) => usize::from(*var_id == IN_VARIABLE_ID),
+        Expr::BinaryOp(left, _, right) => {
+            count_in_variable_refs(engine_state, left) + count_in_variable_refs(engine_state, right)
+        }
+        Expr::UnaryNot(expr) => count_in_variable_refs(engine_state, expr),
+        Expr::Block(block_id) | Expr::Closure(block_id) => {
+            let block = engine_state.get_block(*block_id);
+            usize::from(block.captures.contains(&IN_VARIABLE_ID))
+                + block
+                    .pipelines
+                    .first()
+                    .and_then(|pipeline| pipeline.elements.first())
+                    .map(|element| count_in_variable_refs(engine_state, element.expression()))
+                    .unwrap_or(0)
+        }
+        Expr::RowCondition(block_id) | Expr::Subexpression(block_id) => engine_state
+            .get_block(*block_id)
+            .pipelines
+            .first()
+            .and_then(|pipeline| pipeline.elements.first())
+            .map(|element| count_in_variable_refs(engine_state, element.expression()))
+            .unwrap_or(0),
+        Expr::Call(call) => {
+            call.positional_iter()
+                .map(|positional| count_in_variable_refs(engine_state, positional))
+                .sum::<usize>()
+                + call
+                    .named_iter()
+                    .filter_map(|named| named.2.as_ref())
+                    .map(|expr| count_in_variable_refs(engine_state, expr))
+                    .sum::<usize>()
+        }
+        Expr::ExternalCall(head, args, _) => {
+            count_in_variable_refs(engine_state, head)
+                + args
+                    .iter()
+                    .map(|arg| count_in_variable_refs(engine_state, arg))
+                    .sum::<usize>()
+        }
+        Expr::FullCellPath(full_cell_path) => {
+            count_in_variable_refs(engine_state, &full_cell_path.head)
+        }
+        Expr::Keyword(_, _, expr) | Expr::ValueWithUnit(expr, _) => {
+            count_in_variable_refs(engine_state, expr)
+        }
+        Expr::List(items) | Expr::StringInterpolation(items) => items
+            .iter()
+            .map(|item| count_in_variable_refs(engine_state, item))
+            .sum(),
+        Expr::Range(from, next, to, _) => [from, next, to]
+            .into_iter()
+            .flatten()
+            .map(|expr| count_in_variable_refs(engine_state, expr))
+            .sum(),
+        Expr::Record(fields) => fields
+            .iter()
+            .map(|(name, value)| {
+                count_in_variable_refs(engine_state, name)
+                    + count_in_variable_refs(engine_state, value)
+            })
+            .sum(),
+        Expr::Table(headers, cells) => {
+            headers
+                .iter()
+                .map(|header| count_in_variable_refs(engine_state, header))
+                .sum::<usize>()
+                + cells
+                    .iter()
+                    .flatten()
+                    .map(|cell| count_in_variable_refs(engine_state, cell))
+                    .sum::<usize>()
+        }
+        _ => 0,
+    }
+}
+
 pub fn eval_expression_with_input(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -648,6 +1352,19 @@ pub fn eval_expression_with_input(
     redirect_stdout: bool,
     redirect_stderr: bool,
 ) -> Result<(PipelineData, bool), ShellError> {
+    // A one-shot stream can't back `$in` more than once: when the element
+    // refers to `$in` several times, collect the input up front so every
+    // reference sees the same data. At most one reference keeps streaming.
+    if matches!(
+        input,
+        PipelineData::ListStream(..) | PipelineData::ExternalStream { .. }
+    ) && count_in_variable_refs(engine_state, expr) > 1
+    {
+        let span = input.span().unwrap_or(expr.span);
+        let metadata = input.metadata();
+        input = PipelineData::Value(input.into_value(span), metadata);
+    }
+
     match expr {
         Expression {
             expr: Expr::Call(call),
@@ -707,23 +1424,153 @@ pub fn eval_expression_with_input(
                     .into_pipeline_data()
             }
             _ => {
-                input = eval_expression(engine_state, stack, elem)?.into_pipeline_data();
+                input = match eval_expression(engine_state, stack, elem)? {
+                    Value::Closure { val, .. } if !matches!(input, PipelineData::Empty) => {
+                        eval_closure_stage(
+                            engine_state,
+                            stack,
+                            val,
+                            input,
+                            redirect_stdout,
+                            redirect_stderr,
+                        )?
+                    }
+                    value => value.into_pipeline_data(),
+                };
             }
         },
 
+        elem @ Expression {
+            expr: Expr::Var(_), ..
+        } => {
+            // A variable holding a closure acts as a pipeline stage when it
+            // has input: `ls | $my_filter | sort-by name`
+            input = match eval_expression(engine_state, stack, elem)? {
+                Value::Closure { val, .. } if !matches!(input, PipelineData::Empty) => {
+                    eval_closure_stage(
+                        engine_state,
+                        stack,
+                        val,
+                        input,
+                        redirect_stdout,
+                        redirect_stderr,
+                    )?
+                }
+                value => value.into_pipeline_data(),
+            };
+        }
+
         elem => {
-            input = eval_expression(engine_state, stack, elem)?.into_pipeline_data();
+            let value = eval_expression(engine_state, stack, elem)?;
+            // Path-derived literals carry metadata so downstream commands can
+            // tell an expanded path apart from arbitrary text.
+            let metadata = match &elem.expr {
+                Expr::Filepath(_) | Expr::Directory(_) | Expr::GlobPattern(_) => {
+                    Some(PipelineMetadata {
+                        data_source: DataSource::FilePath,
+                    })
+                }
+                _ => None,
+            };
+            input = PipelineData::Value(value, metadata);
         }
     };
 
     Ok(might_consume_external_result(input))
 }
 
+/// Run a closure held in a variable as a pipeline stage, feeding it the
+/// incoming pipeline data as its input (available as `$in`). The closure is
+/// called without arguments; to pass trailing arguments, call it through `do`
+/// instead.
+fn eval_closure_stage(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    closure: Closure,
+    input: PipelineData,
+    redirect_stdout: bool,
+    redirect_stderr: bool,
+) -> Result<PipelineData, ShellError> {
+    let block = engine_state.get_block(closure.block_id);
+    let mut callee_stack = stack.captures_to_stack(&closure.captures);
+
+    eval_block_with_early_return(
+        engine_state,
+        &mut callee_stack,
+        block,
+        input,
+        redirect_stdout,
+        redirect_stderr,
+    )
+}
+
 // Try to catch and detect if external command runs to failed.
 fn might_consume_external_result(input: PipelineData) -> (PipelineData, bool) {
     input.is_external_failed()
 }
 
+/// Reshape the data of a pipe redirection (`e>|`, `o+e>|`) so the selected
+/// stream(s) continue down the pipe as stdout. Only external streams carry a
+/// separate stderr; other pipeline data has none, so `e>|` pipes nothing.
+fn pipe_redirected_input(input: PipelineData, redirection: &Redirection) -> PipelineData {
+    match (redirection, input) {
+        (
+            Redirection::StderrToPipe,
+            PipelineData::ExternalStream {
+                stderr,
+                exit_code,
+                span,
+                metadata,
+                trim_end_newline,
+                ..
+            },
+        ) => PipelineData::ExternalStream {
+            stdout: stderr,
+            stderr: None,
+            exit_code,
+            span,
+            metadata,
+            trim_end_newline,
+        },
+        (Redirection::StderrToPipe, _) => PipelineData::empty(),
+        (
+            Redirection::StdoutAndStderrToPipe,
+            PipelineData::ExternalStream {
+                stdout,
+                stderr,
+                exit_code,
+                span,
+                metadata,
+                trim_end_newline,
+            },
+        ) => {
+            // with both streams piped, stdout is drained first, then stderr
+            let stdout = match (stdout, stderr) {
+                (Some(out), Some(err)) => {
+                    let ctrlc = out.ctrlc.clone();
+                    Some(RawStream::new(
+                        Box::new(out.stream.chain(err.stream)),
+                        ctrlc,
+                        span,
+                        None,
+                    ))
+                }
+                (None, err) => err,
+                (out, None) => out,
+            };
+            PipelineData::ExternalStream {
+                stdout,
+                stderr: None,
+                exit_code,
+                span,
+                metadata,
+                trim_end_newline,
+            }
+        }
+        (_, input) => input,
+    }
+}
+
 fn eval_element_with_input(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -741,6 +1588,23 @@ fn eval_element_with_input(
             redirect_stdout,
             redirect_stderr,
         ),
+        PipelineElement::Redirection(
+            _,
+            redirection @ (Redirection::StderrToPipe | Redirection::StdoutAndStderrToPipe),
+            expr,
+        ) => {
+            // `e>|` / `o+e>|`: the redirected stream becomes the input of this
+            // element, which is a full command rather than a file target.
+            let input = pipe_redirected_input(input, redirection);
+            eval_expression_with_input(
+                engine_state,
+                stack,
+                expr,
+                input,
+                redirect_stdout,
+                redirect_stderr,
+            )
+        }
         PipelineElement::Redirection(span, redirection, expr) => match &expr.expr {
             Expr::String(_)
             | Expr::FullCellPath(_)
@@ -772,7 +1636,26 @@ fn eval_element_with_input(
                     (_, input) => input,
                 };
 
-                if let Some(save_command) = engine_state.find_decl(b"save", &[]) {
+                // `out> $var` / `err> $var` with a mutable variable captures the
+                // stream into the variable; an immutable variable still names a
+                // file to `save` to, as before.
+                if let Some(var_id) = redirection_target_var(engine_state, expr) {
+                    let value = collect_redirection_capture(input, *span)?;
+                    stack.add_var(var_id, value);
+                    return Ok((
+                        PipelineData::ExternalStream {
+                            stdout: None,
+                            stderr: None,
+                            exit_code,
+                            span: *span,
+                            metadata: None,
+                            trim_end_newline: false,
+                        },
+                        false,
+                    ));
+                }
+
+                if let Some(save_command) = engine_state.find_save_decl() {
                     let save_call = gen_save_call(save_command, (*span, expr.clone()), None);
                     eval_call(engine_state, stack, &save_call, input).map(|_| {
                         // save is internal command, normally it exists with non-ExternalStream
@@ -810,7 +1693,7 @@ fn eval_element_with_input(
                 | Expr::StringInterpolation(_)
                 | Expr::Filepath(_),
             ) => {
-                if let Some(save_command) = engine_state.find_decl(b"save", &[]) {
+                if let Some(save_command) = engine_state.find_save_decl() {
                     let exit_code = match &mut input {
                         PipelineData::ExternalStream { exit_code, .. } => exit_code.take(),
                         _ => None,
@@ -934,6 +1817,196 @@ pub fn eval_block_with_early_return(
     }
 }
 
+/// Run an arbitrary [`Closure`] value with explicitly supplied arguments.
+///
+/// The closure's captures are restored into a fresh stack derived from
+/// `stack` (via `Stack::captures_to_stack`): the block sees the variables it
+/// closed over when it was created, not the caller's current bindings, along
+/// with the caller's environment. `args` are bound positionally to the
+/// block's required and optional parameters in order; extras are collected
+/// into the rest parameter when the signature declares one and are ignored
+/// otherwise, matching `do`. Output is redirected as if the closure ran
+/// inside a pipeline, and `return` inside the block yields the returned
+/// value rather than an error.
+///
+/// This is the eval-layer primitive for invoking closure values that did not
+/// come from a call site, such as blocks constructed at runtime.
+pub fn eval_closure(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    closure: &Closure,
+    input: PipelineData,
+    args: &[Value],
+) -> Result<PipelineData, ShellError> {
+    let block = engine_state.get_block(closure.block_id);
+    let mut callee_stack = stack.captures_to_stack(&closure.captures);
+
+    let params: Vec<_> = block
+        .signature
+        .required_positional
+        .iter()
+        .chain(block.signature.optional_positional.iter())
+        .collect();
+
+    for (param, arg) in params.iter().zip(args) {
+        if let Some(var_id) = param.var_id {
+            callee_stack.add_var(var_id, arg.clone());
+        }
+    }
+
+    if let Some(param) = &block.signature.rest_positional {
+        if args.len() > params.len() {
+            let rest_items = args[params.len()..].to_vec();
+
+            let span = if let Some(rest_item) = rest_items.first() {
+                rest_item.span()
+            } else {
+                Span::unknown()
+            };
+
+            callee_stack.add_var(
+                param
+                    .var_id
+                    .expect("Internal error: rest positional parameter lacks var_id"),
+                Value::list(rest_items, span),
+            )
+        }
+    }
+
+    eval_block_with_early_return(
+        engine_state,
+        &mut callee_stack,
+        block,
+        input,
+        true,
+        false,
+    )
+}
+
+/// Evaluate a block and package the outcome into a record instead of letting
+/// failures unwind: `{ok: value}` on success, `{err: {msg, debug, raw}}` on
+/// failure, with the same error columns a `catch` block receives. Error
+/// values produced by the block count as failures. The control-flow sentinels
+/// (`break`, `continue`, `return`, `exit`) are not failures and still bubble
+/// up as `Err`, so loops and functions enclosing the caller behave normally.
+///
+/// This is the primitive behind a `try-eval`-style expression form, where the
+/// script inspects the outcome inline rather than branching into a catch.
+pub fn eval_block_to_result(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    block: &Block,
+    input: PipelineData,
+    head: Span,
+) -> Result<Value, ShellError> {
+    let is_flow_control = |error: &ShellError| {
+        matches!(
+            error,
+            ShellError::Break(_)
+                | ShellError::Continue(_)
+                | ShellError::Return(_, _)
+                | ShellError::Exit(_, _)
+        )
+    };
+
+    let err_record = |error: ShellError| {
+        Value::record(
+            record! {
+                "err" => Value::record(
+                    record! {
+                        "msg" => Value::string(error.to_string(), head),
+                        "debug" => Value::string(format!("{error:?}"), head),
+                        "raw" => Value::error(error, head),
+                    },
+                    head,
+                ),
+            },
+            head,
+        )
+    };
+
+    match eval_block(engine_state, stack, block, input, false, false) {
+        Err(error) if is_flow_control(&error) => Err(error),
+        Err(error) => Ok(err_record(error)),
+        Ok(data) => match data.into_value(head) {
+            Value::Error { error, .. } if is_flow_control(&error) => Err(*error),
+            Value::Error { error, .. } => Ok(err_record(*error)),
+            value => Ok(Value::record(record! { "ok" => value }, head)),
+        },
+    }
+}
+
+/// Number of list elements a pipeline debug hook gets to see per element
+const DEBUG_PREVIEW_SIZE: usize = 20;
+
+/// Report a pipeline element's output to the debug hook installed on the
+/// stack, if any. Streams of values are collected first so that both the hook
+/// and the rest of the pipeline see the data; external streams and empty
+/// pipelines are passed through untouched since collecting them here would
+/// consume output (and exit codes) that downstream elements rely on.
+fn report_element_output(stack: &Stack, element_span: Span, input: PipelineData) -> PipelineData {
+    let Some(hook) = &stack.pipeline_debug_hook else {
+        return input;
+    };
+
+    match input {
+        PipelineData::Empty | PipelineData::ExternalStream { .. } => {
+            (hook.0)(element_span, Value::nothing(element_span));
+            input
+        }
+        input => {
+            let metadata = input.metadata();
+            let value = input.into_value(element_span);
+            let preview = match &value {
+                Value::List { vals, .. } if vals.len() > DEBUG_PREVIEW_SIZE => {
+                    Value::list(vals[..DEBUG_PREVIEW_SIZE].to_vec(), value.span())
+                }
+                other => other.clone(),
+            };
+            (hook.0)(element_span, preview);
+            PipelineData::Value(value, metadata)
+        }
+    }
+}
+
+/// When the innermost call frame also appears earlier in the call-frame
+/// stack, the recursion hit the depth limit without progressing: the same
+/// command was called with the exact same arguments. Names the commands in
+/// the repeating cycle for a clearer diagnostic than the bare depth error.
+/// Recursion that progresses through its arguments — or through state the
+/// frame can't see, like `$in` or the environment — doesn't match and keeps
+/// the generic [`ShellError::RecursionLimitReached`].
+fn detect_recursion_cycle(
+    engine_state: &EngineState,
+    stack: &Stack,
+    span: Option<Span>,
+) -> Option<ShellError> {
+    let frames = &stack.call_frames;
+    let frame = frames.last()?;
+    let first_repeat = frames[..frames.len() - 1].iter().position(|f| f == frame)?;
+    // One full period of the cycle is enough for the diagnostic; it ends at
+    // the frame's next occurrence (at the latest, the innermost call itself).
+    let cycle_end = first_repeat
+        + 1
+        + frames[first_repeat + 1..]
+            .iter()
+            .position(|f| f == frame)
+            .expect("internal error: repeated call frame must recur");
+
+    let mut cycle = vec![];
+    for (decl_id, _) in &frames[first_repeat..=cycle_end] {
+        let name = engine_state.get_decl(*decl_id).name().to_string();
+        if cycle.last() != Some(&name) {
+            cycle.push(name);
+        }
+    }
+
+    Some(ShellError::InfiniteRecursion {
+        cycle: cycle.join(" -> "),
+        span: span.unwrap_or_else(Span::unknown),
+    })
+}
+
 pub fn eval_block(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -949,6 +2022,9 @@ pub fn eval_block(
         if recursive {
             if *stack.recursion_count >= RECURSION_LIMIT {
                 stack.recursion_count = Box::new(0);
+                if let Some(cycle) = detect_recursion_cycle(engine_state, stack, block.span) {
+                    return Err(cycle);
+                }
                 return Err(ShellError::RecursionLimitReached {
                     recursion_limit: RECURSION_LIMIT,
                     span: block.span,
@@ -969,6 +2045,8 @@ pub fn eval_block(
                         next_element,
                         PipelineElement::Redirection(_, Redirection::Stderr, _)
                             | PipelineElement::Redirection(_, Redirection::StdoutAndStderr, _)
+                            | PipelineElement::Redirection(_, Redirection::StderrToPipe, _)
+                            | PipelineElement::Redirection(_, Redirection::StdoutAndStderrToPipe, _)
                             | PipelineElement::SeparateRedirection { .. }
                     )
                 }));
@@ -979,11 +2057,29 @@ pub fn eval_block(
                         next_element,
                         PipelineElement::Redirection(_, Redirection::Stdout, _)
                             | PipelineElement::Redirection(_, Redirection::StdoutAndStderr, _)
+                            | PipelineElement::Redirection(_, Redirection::StdoutAndStderrToPipe, _)
                             | PipelineElement::Expression(..)
                             | PipelineElement::SeparateRedirection { .. }
                     )
                 }));
 
+            // `&&`/`||` boundaries consult the previous element's result before
+            // running their expression: `And` runs only after success, `Or`
+            // only after failure. A skipped element passes the previous data
+            // through unchanged, so the pipeline still reports the outcome
+            // that caused the skip.
+            if let PipelineElement::And(..) | PipelineElement::Or(..) = element {
+                let (data, success) = element_success(stack, input)?;
+                input = data;
+                let skip = match element {
+                    PipelineElement::And(..) => !success,
+                    _ => success,
+                };
+                if skip {
+                    continue;
+                }
+            }
+
             // if eval internal command failed, it can just make early return with `Err(ShellError)`.
             let eval_result = eval_element_with_input(
                 engine_state,
@@ -998,6 +2094,10 @@ pub fn eval_block(
                 (Ok((pipeline_data, _)), true) => {
                     input = pipeline_data;
 
+                    if stack.pipeline_debug_hook.is_some() {
+                        input = report_element_output(stack, element.span(), input);
+                    }
+
                     // external command may runs to failed
                     // make early return so remaining commands will not be executed.
                     // don't return `Err(ShellError)`, so nushell wouldn't show extra error message.
@@ -1014,10 +2114,33 @@ pub fn eval_block(
                 (output, false) => {
                     let output = output?;
                     input = output.0;
+
+                    if stack.pipeline_debug_hook.is_some() {
+                        input = report_element_output(stack, element.span(), input);
+                    }
                     // external command may runs to failed
                     // make early return so remaining commands will not be executed.
                     // don't return `Err(ShellError)`, so nushell wouldn't show extra error message.
                     if output.1 {
+                        // The external failed and the remaining elements are
+                        // skipped; record the exit code before bailing out so
+                        // enclosing scopes can still inspect $env.LAST_EXIT_CODE.
+                        // The code stream is already collected at this point, so
+                        // peeking and rebuilding it loses nothing.
+                        if let PipelineData::ExternalStream {
+                            ref mut exit_code, ..
+                        } = input
+                        {
+                            if let Some(stream) = exit_code.take() {
+                                let ctrlc = stream.ctrlc.clone();
+                                let codes: Vec<Value> = stream.into_iter().collect();
+                                if let Some(last) = codes.last() {
+                                    stack.add_env_var("LAST_EXIT_CODE".into(), last.clone());
+                                }
+                                *exit_code =
+                                    Some(ListStream::from_stream(codes.into_iter(), ctrlc));
+                            }
+                        }
                         return Ok(input);
                     }
                 }
@@ -1042,7 +2165,28 @@ pub fn eval_block(
                         }
                     }
                 }
-                _ => input.drain()?,
+                _ => {
+                    // Data other than nothing reaching a pipeline boundary is
+                    // about to be dropped on the floor, which is often a bug in
+                    // the script. With a warning hook installed, report it
+                    // before draining; without one, drop it silently as before.
+                    if let Some(hook) = &stack.warning_hook {
+                        if !matches!(input, PipelineData::Empty) {
+                            let span = match (pipeline.elements.first(), pipeline.elements.last()) {
+                                (Some(first), Some(last)) => {
+                                    Span::new(first.span().start, last.span().end)
+                                }
+                                _ => Span::unknown(),
+                            };
+                            (hook.0)(
+                                "this pipeline's result is discarded before the next pipeline runs"
+                                    .into(),
+                                span,
+                            );
+                        }
+                    }
+                    input.drain()?
+                }
             }
 
             input = PipelineData::empty()
@@ -1052,6 +2196,72 @@ pub fn eval_block(
     Ok(input)
 }
 
+/// Whether the data left behind by the previous pipeline element counts as
+/// success at an `&&`/`||` boundary. Internal commands succeed unless they
+/// produced an error value (outright failures already abort the block as
+/// `Err`); externals succeed on exit code 0. Learning an external's exit code
+/// means draining its stream, so the caller gets replacement input back:
+/// empty for a drained external (its output is not the next command's input,
+/// matching shell `&&` semantics), the original data otherwise.
+fn element_success(
+    stack: &mut Stack,
+    mut input: PipelineData,
+) -> Result<(PipelineData, bool), ShellError> {
+    match input {
+        PipelineData::ExternalStream {
+            ref mut exit_code, ..
+        } => {
+            let exit_code = exit_code.take();
+
+            input.drain()?;
+
+            let mut success = true;
+            if let Some(exit_code) = exit_code {
+                let mut v: Vec<_> = exit_code.collect();
+
+                if let Some(v) = v.pop() {
+                    success = matches!(v, Value::Int { val: 0, .. });
+                    stack.add_env_var("LAST_EXIT_CODE".into(), v);
+                }
+            }
+
+            Ok((PipelineData::empty(), success))
+        }
+        PipelineData::Value(Value::Error { .. }, ..) => Ok((input, false)),
+        _ => Ok((input, true)),
+    }
+}
+
+/// Evaluate a block with extra variables pre-bound on the stack.
+///
+/// This is `eval_block` for embedders: hosts driving the engine directly (test
+/// harnesses, library consumers) can seed context variables before evaluation
+/// instead of threading everything through `PipelineData`. The bindings are
+/// plain `Stack::add_var` calls, so they shadow any captures with the same id
+/// and remain on the stack afterwards.
+pub fn eval_block_with_bindings(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    block: &Block,
+    bindings: &HashMap<VarId, Value>,
+    input: PipelineData,
+    redirect_stdout: bool,
+    redirect_stderr: bool,
+) -> Result<PipelineData, ShellError> {
+    for (var_id, value) in bindings {
+        stack.add_var(*var_id, value.clone());
+    }
+
+    eval_block(
+        engine_state,
+        stack,
+        block,
+        input,
+        redirect_stdout,
+        redirect_stderr,
+    )
+}
+
 pub fn eval_subexpression(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -1064,6 +2274,46 @@ pub fn eval_subexpression(
         }
     }
 
+    // A captured external's exit code would otherwise be dropped when the
+    // caller collects the output; record it like `eval_block` does at pipeline
+    // boundaries so `$env.LAST_EXIT_CODE` still reflects the subexpression.
+    // run-external only sends the exit code after forwarding all of stdout, so
+    // waiting for it while `stdout` is still unconsumed would deadlock once
+    // the output outgrows the pipe and channel buffers — the hazard
+    // `is_external_failed` documents. Like there, the code is only collected
+    // once stdout is gone, with stderr consumed and rebuilt first because it
+    // is sent ahead of the exit code on the same thread.
+    if let PipelineData::ExternalStream {
+        stdout: None,
+        ref mut stderr,
+        ref mut exit_code,
+        ..
+    } = input
+    {
+        if let Some(stderr_stream) = stderr.take() {
+            let stderr_ctrlc = stderr_stream.ctrlc.clone();
+            let stderr_span = stderr_stream.span;
+            let stderr_bytes = stderr_stream
+                .into_bytes()
+                .map(|bytes| bytes.item)
+                .unwrap_or_default();
+            *stderr = Some(RawStream::new(
+                Box::new(vec![Ok(stderr_bytes)].into_iter()),
+                stderr_ctrlc,
+                stderr_span,
+                None,
+            ));
+        }
+        if let Some(stream) = exit_code.take() {
+            let ctrlc = stream.ctrlc.clone();
+            let codes: Vec<Value> = stream.into_iter().collect();
+            if let Some(last) = codes.last() {
+                stack.add_env_var("LAST_EXIT_CODE".into(), last.clone());
+            }
+            *exit_code = Some(ListStream::from_stream(codes.into_iter(), ctrlc));
+        }
+    }
+
     Ok(input)
 }
 
@@ -1079,7 +2329,10 @@ pub fn eval_variable(
             if let Some(val) = engine_state.get_constant(var_id) {
                 Ok(val.clone())
             } else {
-                Err(ShellError::VariableNotFoundAtRuntime { span })
+                Err(ShellError::VariableNotFoundAtRuntime {
+                    suggestion: DidYouMean::from(None),
+                    span,
+                })
             }
         }
         // $env
@@ -1097,14 +2350,168 @@ pub fn eval_variable(
 
             Ok(Value::record(pairs.into_iter().collect(), span))
         }
-        var_id => stack.get_var(var_id, span),
+        var_id => stack
+            .get_var(var_id, span)
+            .map_err(|_| variable_not_found(engine_state, var_id, span)),
     }
 }
 
+/// Enforce the embedder-set cap on literal collection sizes (see
+/// [`EngineState::max_collection_size`]); `what` names the literal kind in the
+/// error. With no limit configured this is a no-op.
+fn check_collection_size(
+    engine_state: &EngineState,
+    size: usize,
+    what: &str,
+    span: Span,
+) -> Result<(), ShellError> {
+    match engine_state.max_collection_size {
+        Some(limit) if size > limit => Err(ShellError::GenericError(
+            "Collection size limit exceeded".into(),
+            format!("this {what} has {size} entries, over the host's limit of {limit}"),
+            Some(span),
+            None,
+            Vec::new(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// A [`ShellError::VariableNotFoundAtRuntime`] carrying a "did you mean"
+/// suggestion computed by edit distance over the variable names currently in
+/// scope. The missing variable's own name is recovered by reverse lookup of
+/// its id in the active overlays.
+fn variable_not_found(engine_state: &EngineState, var_id: VarId, span: Span) -> ShellError {
+    let mut names: Vec<&[u8]> = vec![];
+    let mut missing_name: Option<&[u8]> = None;
+    for overlay_frame in engine_state.active_overlays(&[]) {
+        for (name, id) in &overlay_frame.vars {
+            if *id == var_id {
+                missing_name = Some(name);
+            } else {
+                names.push(name);
+            }
+        }
+    }
+
+    let suggestion = match missing_name {
+        Some(name) => DidYouMean::new(&names, name),
+        None => DidYouMean::from(None),
+    };
+    ShellError::VariableNotFoundAtRuntime { suggestion, span }
+}
+
 fn compute(size: i64, unit: Unit, span: Span) -> Result<Value, ShellError> {
     unit.to_value(size, span)
 }
 
+/// Renders an interpolated value according to a format spec: `.2f` formats a
+/// number with the given precision, `05d` zero-pads an int to the given width,
+/// and `x`/`X`/`o`/`b` render an int in the given base. Specs that don't apply
+/// to the value's type fall back to the default formatting.
+fn format_value_with_spec(
+    value: &Value,
+    spec: &str,
+    config: &Config,
+    span: Span,
+) -> Result<String, ShellError> {
+    match spec.as_bytes() {
+        [b'.', .., b'f'] => {
+            if let Ok(precision) = spec[1..spec.len() - 1].parse::<usize>() {
+                if let Ok(val) = value.as_float() {
+                    return Ok(format!("{val:.precision$}"));
+                }
+            }
+        }
+        [b'0', .., b'd'] => {
+            if let Ok(width) = spec[1..spec.len() - 1].parse::<usize>() {
+                if let Ok(val) = value.as_int() {
+                    return Ok(format!("{val:0width$}"));
+                }
+            }
+        }
+        [b'x'] => {
+            if let Ok(val) = value.as_int() {
+                return Ok(format!("{val:x}"));
+            }
+        }
+        [b'X'] => {
+            if let Ok(val) = value.as_int() {
+                return Ok(format!("{val:X}"));
+            }
+        }
+        [b'o'] => {
+            if let Ok(val) = value.as_int() {
+                return Ok(format!("{val:o}"));
+            }
+        }
+        [b'b'] => {
+            if let Ok(val) = value.as_int() {
+                return Ok(format!("{val:b}"));
+            }
+        }
+        _ => {}
+    }
+
+    let _ = span;
+    Ok(value.into_string("", config))
+}
+
+/// Byte cap for `err> $var` style captures. The whole stream is buffered in
+/// memory before it is assigned, so a runaway stream errors out instead of
+/// exhausting memory.
+const REDIRECT_CAPTURE_LIMIT: usize = 64 * 1024 * 1024;
+
+/// If a redirection target is a bare mutable variable (`out> $var`), return
+/// its id; any other target keeps the file-redirection semantics.
+fn redirection_target_var(engine_state: &EngineState, expr: &Expression) -> Option<VarId> {
+    if let Expr::FullCellPath(full_cell_path) = &expr.expr {
+        if full_cell_path.tail.is_empty() {
+            if let Expr::Var(var_id) = full_cell_path.head.expr {
+                if engine_state.get_var(var_id).mutable {
+                    return Some(var_id);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Collect a redirected stream into a single string value for assignment to a
+/// variable, enforcing [`REDIRECT_CAPTURE_LIMIT`].
+fn collect_redirection_capture(input: PipelineData, span: Span) -> Result<Value, ShellError> {
+    match input {
+        PipelineData::ExternalStream {
+            stdout: Some(stream),
+            trim_end_newline,
+            ..
+        } => {
+            let mut bytes = vec![];
+            for chunk in stream.stream {
+                bytes.extend_from_slice(&chunk?);
+                if bytes.len() > REDIRECT_CAPTURE_LIMIT {
+                    return Err(ShellError::GenericError(
+                        "Redirected output is too large to capture into a variable".into(),
+                        format!("exceeds the {REDIRECT_CAPTURE_LIMIT} byte capture limit"),
+                        Some(span),
+                        Some("redirect to a file instead".into()),
+                        Vec::new(),
+                    ));
+                }
+            }
+            let mut captured = String::from_utf8_lossy(&bytes).into_owned();
+            if trim_end_newline {
+                captured.truncate(captured.trim_end_matches('\n').len());
+            }
+            Ok(Value::string(captured, span))
+        }
+        PipelineData::ExternalStream { stdout: None, .. } | PipelineData::Empty => {
+            Ok(Value::string("", span))
+        }
+        input => Ok(input.into_value(span)),
+    }
+}
+
 fn gen_save_call(
     save_decl_id: DeclId,
     out_info: (Span, Expression),
@@ -1150,3 +2557,196 @@ fn gen_save_call(
         parser_info: HashMap::new(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int_expr(val: i64) -> Expression {
+        Expression {
+            expr: Expr::Int(val),
+            span: Span::test_data(),
+            ty: Type::Int,
+            custom_completion: None,
+        }
+    }
+
+    fn list_expr(len: i64) -> Expression {
+        Expression {
+            expr: Expr::List((0..len).map(int_expr).collect()),
+            span: Span::test_data(),
+            ty: Type::List(Box::new(Type::Int)),
+            custom_completion: None,
+        }
+    }
+
+    #[test]
+    fn list_literal_over_collection_size_limit_is_rejected() {
+        let mut engine_state = EngineState::new();
+        engine_state.max_collection_size = Some(2);
+        let mut stack = Stack::new();
+
+        assert!(eval_expression(&engine_state, &mut stack, &list_expr(3)).is_err());
+    }
+
+    #[test]
+    fn list_literal_within_collection_size_limit_is_allowed() {
+        let mut engine_state = EngineState::new();
+        engine_state.max_collection_size = Some(2);
+        let mut stack = Stack::new();
+
+        assert!(eval_expression(&engine_state, &mut stack, &list_expr(2)).is_ok());
+    }
+
+    #[test]
+    fn collection_size_is_unlimited_by_default() {
+        let engine_state = EngineState::new();
+        let mut stack = Stack::new();
+
+        assert!(eval_expression(&engine_state, &mut stack, &list_expr(100)).is_ok());
+    }
+
+    #[test]
+    fn input_coercion_promotes_int_to_declared_float() {
+        let signature = Signature::build("test").input_output_types(vec![(Type::Float, Type::Any)]);
+        let input = PipelineData::Value(Value::test_int(3), None);
+
+        let result = coerce_input_to_signature(&signature, input, Span::test_data())
+            .and_then(|data| data.into_value(Span::test_data()).as_float());
+
+        assert_eq!(result.ok(), Some(3.0));
+    }
+
+    #[test]
+    fn input_coercion_rejects_unrelated_types() {
+        let signature =
+            Signature::build("test").input_output_types(vec![(Type::String, Type::Any)]);
+        let input = PipelineData::Value(Value::test_list(vec![]), None);
+
+        assert!(coerce_input_to_signature(&signature, input, Span::test_data()).is_err());
+    }
+
+    #[test]
+    fn input_coercion_passes_matching_input_through() {
+        let signature = Signature::build("test").input_output_types(vec![(Type::Int, Type::Any)]);
+        let input = PipelineData::Value(Value::test_int(3), None);
+
+        let result = coerce_input_to_signature(&signature, input, Span::test_data())
+            .and_then(|data| data.into_value(Span::test_data()).as_int());
+
+        assert_eq!(result.ok(), Some(3));
+    }
+
+    fn two_pipeline_block() -> Block {
+        use nu_protocol::ast::Pipeline;
+
+        let mut block = Block::new();
+        for val in [1, 2] {
+            let mut pipeline = Pipeline::new();
+            pipeline
+                .elements
+                .push(PipelineElement::Expression(None, int_expr(val)));
+            block.pipelines.push(pipeline);
+        }
+        block
+    }
+
+    fn one_pipeline_block(expr: Expression) -> Block {
+        use nu_protocol::ast::Pipeline;
+
+        let mut block = Block::new();
+        let mut pipeline = Pipeline::new();
+        pipeline
+            .elements
+            .push(PipelineElement::Expression(None, expr));
+        block.pipelines.push(pipeline);
+        block
+    }
+
+    #[test]
+    fn eval_block_to_result_wraps_success_in_ok() {
+        let engine_state = EngineState::new();
+        let mut stack = Stack::new();
+
+        let result = eval_block_to_result(
+            &engine_state,
+            &mut stack,
+            &one_pipeline_block(int_expr(5)),
+            PipelineData::empty(),
+            Span::test_data(),
+        );
+
+        let value = result.expect("success is not an Err");
+        let record = value.as_record().unwrap();
+        assert_eq!(record.get("ok"), Some(&Value::test_int(5)));
+    }
+
+    #[test]
+    fn eval_block_to_result_wraps_failure_in_err_record() {
+        let engine_state = EngineState::new();
+        let mut stack = Stack::new();
+        let missing_var = Expression {
+            expr: Expr::Var(999),
+            span: Span::test_data(),
+            ty: Type::Any,
+            custom_completion: None,
+        };
+
+        let result = eval_block_to_result(
+            &engine_state,
+            &mut stack,
+            &one_pipeline_block(missing_var),
+            PipelineData::empty(),
+            Span::test_data(),
+        );
+
+        let value = result.expect("failure is packaged, not an Err");
+        let record = value.as_record().unwrap();
+        assert!(record.get("err").is_some());
+    }
+
+    #[test]
+    fn warning_hook_reports_data_dropped_between_pipelines() {
+        use nu_protocol::engine::WarningHook;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let engine_state = EngineState::new();
+        let mut stack = Stack::new();
+        let warnings = Arc::new(AtomicUsize::new(0));
+        let counter = warnings.clone();
+        stack.warning_hook = Some(WarningHook(Arc::new(move |_, _| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        })));
+
+        let result = eval_block(
+            &engine_state,
+            &mut stack,
+            &two_pipeline_block(),
+            PipelineData::empty(),
+            false,
+            false,
+        );
+
+        assert!(result.is_ok());
+        // only the first pipeline's result hits a boundary; the second is returned
+        assert_eq!(warnings.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn dropped_data_between_pipelines_is_silent_by_default() {
+        let engine_state = EngineState::new();
+        let mut stack = Stack::new();
+
+        let result = eval_block(
+            &engine_state,
+            &mut stack,
+            &two_pipeline_block(),
+            PipelineData::empty(),
+            false,
+            false,
+        );
+
+        assert!(result.is_ok());
+    }
+}
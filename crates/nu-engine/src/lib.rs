@@ -11,7 +11,10 @@ pub use column::get_columns;
 pub use documentation::get_full_help;
 pub use env::*;
 pub use eval::{
-    eval_block, eval_block_with_early_return, eval_call, eval_expression,
-    eval_expression_with_input, eval_subexpression, eval_variable, redirect_env,
+    eval_block, eval_block_to_result, eval_block_with_bindings, eval_block_with_early_return,
+    eval_call, eval_closure, eval_constant, eval_expression,
+    eval_expression_pure, eval_expression_with_cache, eval_expression_with_input,
+    eval_subexpression, eval_variable,
+    expression_is_pure, expression_references_in, redirect_env, ExpressionCache,
 };
 pub use glob_from::glob_from;
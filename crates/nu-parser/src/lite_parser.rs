@@ -147,7 +147,9 @@ impl LiteBlock {
                 match *redirection {
                     Redirection::Stderr => stderr_index = Some(index),
                     Redirection::Stdout => stdout_index = Some(index),
-                    Redirection::StdoutAndStderr => {}
+                    Redirection::StdoutAndStderr
+                    | Redirection::StderrToPipe
+                    | Redirection::StdoutAndStderrToPipe => {}
                 }
             }
         }
@@ -215,6 +217,10 @@ pub fn lite_parse(tokens: &[Token]) -> (LiteBlock, Option<ParseError>) {
 
     let mut curr_comment: Option<Vec<Span>> = None;
 
+    // set when a `|` directly follows a redirection operator (`e>|`, `o+e>|`),
+    // so the next command receives the redirected stream as its input
+    let mut pipe_redirection: Option<Redirection> = None;
+
     let mut error = None;
 
     for (idx, token) in tokens.iter().enumerate() {
@@ -240,6 +246,7 @@ pub fn lite_parse(tokens: &[Token]) -> (LiteBlock, Option<ParseError>) {
                     curr_command,
                     last_connector,
                     last_connector_span,
+                    &mut pipe_redirection,
                 ) {
                     error = Some(err);
                 }
@@ -250,19 +257,38 @@ pub fn lite_parse(tokens: &[Token]) -> (LiteBlock, Option<ParseError>) {
                 last_connector_span = Some(token.span);
             }
             TokenContents::Pipe => {
-                if let Some(err) = push_command_to(
-                    &mut curr_pipeline,
-                    curr_command,
-                    last_connector,
-                    last_connector_span,
-                ) {
-                    error = Some(err);
-                }
+                if curr_command.is_empty()
+                    && matches!(
+                        last_connector,
+                        TokenContents::ErrGreaterThan | TokenContents::OutErrGreaterThan
+                    )
+                {
+                    // `e>|` / `o+e>|`: the redirected stream is piped into the
+                    // next command instead of going to a file. Keep the span of
+                    // the redirection operator as the connector span.
+                    pipe_redirection = Some(if last_connector == TokenContents::ErrGreaterThan {
+                        Redirection::StderrToPipe
+                    } else {
+                        Redirection::StdoutAndStderrToPipe
+                    });
+                    last_token = TokenContents::Pipe;
+                    last_connector = TokenContents::Pipe;
+                } else {
+                    if let Some(err) = push_command_to(
+                        &mut curr_pipeline,
+                        curr_command,
+                        last_connector,
+                        last_connector_span,
+                        &mut pipe_redirection,
+                    ) {
+                        error = Some(err);
+                    }
 
-                curr_command = LiteCommand::new();
-                last_token = TokenContents::Pipe;
-                last_connector = TokenContents::Pipe;
-                last_connector_span = Some(token.span);
+                    curr_command = LiteCommand::new();
+                    last_token = TokenContents::Pipe;
+                    last_connector = TokenContents::Pipe;
+                    last_connector_span = Some(token.span);
+                }
             }
             TokenContents::Eol => {
                 // Handle `[Command] [Pipe] ([Comment] | [Eol])+ [Command]`
@@ -278,6 +304,7 @@ pub fn lite_parse(tokens: &[Token]) -> (LiteBlock, Option<ParseError>) {
                         curr_command,
                         last_connector,
                         last_connector_span,
+                        &mut pipe_redirection,
                     ) {
                         error = Some(err);
                     }
@@ -305,6 +332,7 @@ pub fn lite_parse(tokens: &[Token]) -> (LiteBlock, Option<ParseError>) {
                     curr_command,
                     last_connector,
                     last_connector_span,
+                    &mut pipe_redirection,
                 ) {
                     error = Some(err);
                 }
@@ -344,6 +372,7 @@ pub fn lite_parse(tokens: &[Token]) -> (LiteBlock, Option<ParseError>) {
         curr_command,
         last_connector,
         last_connector_span,
+        &mut pipe_redirection,
     ) {
         error = Some(err);
     }
@@ -373,8 +402,18 @@ fn push_command_to(
     command: LiteCommand,
     last_connector: TokenContents,
     last_connector_span: Option<Span>,
+    pipe_redirection: &mut Option<Redirection>,
 ) -> Option<ParseError> {
     if !command.is_empty() {
+        if let Some(redirection) = pipe_redirection.take() {
+            pipeline.push(LiteElement::Redirection(
+                last_connector_span
+                    .expect("internal error: redirection missing span information"),
+                redirection,
+                command,
+            ));
+            return None;
+        }
         match last_connector {
             TokenContents::OutGreaterThan => {
                 pipeline.push(LiteElement::Redirection(
@@ -406,6 +445,12 @@ fn push_command_to(
         }
         None
     } else {
+        if pipe_redirection.take().is_some() {
+            return Some(ParseError::Expected(
+                "command",
+                last_connector_span.expect("internal error: redirection missing span information"),
+            ));
+        }
         match last_connector {
             TokenContents::OutGreaterThan
             | TokenContents::ErrGreaterThan
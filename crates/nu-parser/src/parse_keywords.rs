@@ -3043,7 +3043,7 @@ pub fn parse_let(working_set: &mut StateWorkingSet, spans: &[Span]) -> Pipeline
                             .trim_start_matches('$')
                             .to_string();
 
-                    if ["in", "nu", "env"].contains(&var_name.as_str()) {
+                    if ["in", "nu", "env", "index"].contains(&var_name.as_str()) {
                         working_set.error(ParseError::NameIsBuiltinVar(var_name, lvalue.span))
                     }
 
@@ -3151,7 +3151,7 @@ pub fn parse_const(working_set: &mut StateWorkingSet, spans: &[Span]) -> Pipelin
                             .to_string();
 
                     // TODO: Remove the hard-coded variables, too error-prone
-                    if ["in", "nu", "env"].contains(&var_name.as_str()) {
+                    if ["in", "nu", "env", "index"].contains(&var_name.as_str()) {
                         working_set.error(ParseError::NameIsBuiltinVar(var_name, lvalue.span))
                     }
 
@@ -3292,7 +3292,7 @@ pub fn parse_mut(working_set: &mut StateWorkingSet, spans: &[Span]) -> Pipeline
                             .trim_start_matches('$')
                             .to_string();
 
-                    if ["in", "nu", "env"].contains(&var_name.as_str()) {
+                    if ["in", "nu", "env", "index"].contains(&var_name.as_str()) {
                         working_set.error(ParseError::NameIsBuiltinVar(var_name, lvalue.span))
                     }
 
@@ -1614,6 +1614,21 @@ pub fn parse_brace_expr(
     }
 }
 
+/// Checks whether the contents of a parenthesized interpolation part form a
+/// format spec rather than an expression. Specs carry a leading `:` sigil so
+/// they can never be mistaken for a command call or variable: `:.2f` (float
+/// precision), `:05d` (zero-padded int), or one of `:x`/`:X`/`:o`/`:b` (int
+/// base).
+fn is_interpolation_format_spec(contents: &[u8]) -> bool {
+    match contents {
+        [b':', b'.', digits @ .., b'f'] | [b':', b'0', digits @ .., b'd'] => {
+            !digits.is_empty() && digits.iter().all(u8::is_ascii_digit)
+        }
+        [b':', b'x'] | [b':', b'X'] | [b':', b'o'] | [b':', b'b'] => true,
+        _ => false,
+    }
+}
+
 pub fn parse_string_interpolation(working_set: &mut StateWorkingSet, span: Span) -> Expression {
     #[derive(PartialEq, Eq, Debug)]
     enum InterpolationMode {
@@ -1728,8 +1743,31 @@ pub fn parse_string_interpolation(working_set: &mut StateWorkingSet, span: Span)
                     if token_start < b {
                         let span = Span::new(token_start, b + 1);
 
-                        let expr = parse_full_cell_path(working_set, None, span);
-                        output.push(expr);
+                        // A parenthesized part like `(:.2f)` is not an expression but
+                        // a format spec applied to the value of the following part.
+                        // Mark it with a custom type so eval can tell it apart from an
+                        // ordinary string part. The `:` sigil and the requirement that
+                        // another parenthesized part follows keep every sigil-less
+                        // part — `(x)`, `(b)`, command calls, variables — an ordinary
+                        // subexpression with its existing meaning.
+                        let part_contents = &contents[(token_start - start + 1)..(b - start)];
+                        let followed_by_expression =
+                            b + 1 != end && contents[b + 1 - start] == b'(';
+                        if followed_by_expression && is_interpolation_format_spec(part_contents) {
+                            output.push(Expression {
+                                // the sigil is parse-time syntax only; eval sees the
+                                // bare spec
+                                expr: Expr::String(
+                                    String::from_utf8_lossy(&part_contents[1..]).to_string(),
+                                ),
+                                span,
+                                ty: Type::Custom("format-spec".into()),
+                                custom_completion: None,
+                            });
+                        } else {
+                            let expr = parse_full_cell_path(working_set, None, span);
+                            output.push(expr);
+                        }
                     }
 
                     token_start = b + 1;
@@ -1805,6 +1843,13 @@ pub fn parse_variable_expr(working_set: &mut StateWorkingSet, span: Span) -> Exp
             ty: Type::Any,
             custom_completion: None,
         };
+    } else if contents == b"$index" {
+        return Expression {
+            expr: Expr::Var(nu_protocol::INDEX_VARIABLE_ID),
+            span,
+            ty: Type::Int,
+            custom_completion: None,
+        };
     }
 
     let name = if contents.starts_with(b"$") {
@@ -5278,7 +5323,14 @@ pub fn parse_pipeline(
                     PipelineElement::Expression(*span, expr)
                 }
                 LiteElement::Redirection(span, redirection, command) => {
-                    let expr = parse_value(working_set, command.parts[0], &SyntaxShape::Any);
+                    // `e>|` / `o+e>|` pipe into a full command; the file
+                    // redirections take a single value naming the target.
+                    let expr = match redirection {
+                        Redirection::StderrToPipe | Redirection::StdoutAndStderrToPipe => {
+                            parse_expression(working_set, &command.parts, is_subexpression)
+                        }
+                        _ => parse_value(working_set, command.parts[0], &SyntaxShape::Any),
+                    };
 
                     PipelineElement::Redirection(*span, redirection.clone(), expr)
                 }
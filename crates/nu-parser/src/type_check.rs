@@ -67,6 +67,24 @@ pub fn type_compatible(lhs: &Type, rhs: &Type) -> bool {
     }
 }
 
+/// Whether an expression is itself an ordering comparison, making it the left
+/// link of a chained comparison like `0 < $x < 10`.
+fn is_ordering_comparison(expr: &Expression) -> bool {
+    if let Expr::BinaryOp(_, op, _) = &expr.expr {
+        matches!(
+            &op.expr,
+            Expr::Operator(Operator::Comparison(
+                Comparison::LessThan
+                    | Comparison::LessThanOrEqual
+                    | Comparison::GreaterThan
+                    | Comparison::GreaterThanOrEqual
+            ))
+        )
+    } else {
+        false
+    }
+}
+
 pub fn math_result_type(
     _working_set: &StateWorkingSet,
     lhs: &mut Expression,
@@ -89,6 +107,11 @@ pub fn math_result_type(
                 (Type::Date, Type::Duration) => (Type::Date, None),
                 (Type::Duration, Type::Duration) => (Type::Duration, None),
                 (Type::Filesize, Type::Filesize) => (Type::Filesize, None),
+                // the int is coerced into the unit-carrying type at eval time
+                (Type::Int, Type::Duration) => (Type::Duration, None),
+                (Type::Duration, Type::Int) => (Type::Duration, None),
+                (Type::Int, Type::Filesize) => (Type::Filesize, None),
+                (Type::Filesize, Type::Int) => (Type::Filesize, None),
 
                 (Type::Custom(a), Type::Custom(b)) if a == b => (Type::Custom(a.to_string()), None),
                 (Type::Custom(a), _) => (Type::Custom(a.to_string()), None),
@@ -190,6 +213,11 @@ pub fn math_result_type(
                 (Type::Date, Type::Duration) => (Type::Date, None),
                 (Type::Duration, Type::Duration) => (Type::Duration, None),
                 (Type::Filesize, Type::Filesize) => (Type::Filesize, None),
+                // the int is coerced into the unit-carrying type at eval time
+                (Type::Int, Type::Duration) => (Type::Duration, None),
+                (Type::Duration, Type::Int) => (Type::Duration, None),
+                (Type::Int, Type::Filesize) => (Type::Filesize, None),
+                (Type::Filesize, Type::Int) => (Type::Filesize, None),
 
                 (Type::Custom(a), Type::Custom(b)) if a == b => (Type::Custom(a.to_string()), None),
                 (Type::Custom(a), _) => (Type::Custom(a.to_string()), None),
@@ -465,6 +493,8 @@ pub fn math_result_type(
                 }
             }
             Operator::Comparison(Comparison::LessThan) => match (&lhs.ty, &rhs.ty) {
+                // chained comparison: `0 < $x < 10` means `0 < $x and $x < 10`
+                _ if is_ordering_comparison(lhs) => (Type::Bool, None),
                 (Type::Int, Type::Int) => (Type::Bool, None),
                 (Type::Float, Type::Int) => (Type::Bool, None),
                 (Type::Int, Type::Float) => (Type::Bool, None),
@@ -514,6 +544,8 @@ pub fn math_result_type(
                 }
             },
             Operator::Comparison(Comparison::LessThanOrEqual) => match (&lhs.ty, &rhs.ty) {
+                // chained comparison: `0 < $x < 10` means `0 < $x and $x < 10`
+                _ if is_ordering_comparison(lhs) => (Type::Bool, None),
                 (Type::Int, Type::Int) => (Type::Bool, None),
                 (Type::Float, Type::Int) => (Type::Bool, None),
                 (Type::Int, Type::Float) => (Type::Bool, None),
@@ -563,6 +595,8 @@ pub fn math_result_type(
                 }
             },
             Operator::Comparison(Comparison::GreaterThan) => match (&lhs.ty, &rhs.ty) {
+                // chained comparison: `0 < $x < 10` means `0 < $x and $x < 10`
+                _ if is_ordering_comparison(lhs) => (Type::Bool, None),
                 (Type::Int, Type::Int) => (Type::Bool, None),
                 (Type::Float, Type::Int) => (Type::Bool, None),
                 (Type::Int, Type::Float) => (Type::Bool, None),
@@ -612,6 +646,8 @@ pub fn math_result_type(
                 }
             },
             Operator::Comparison(Comparison::GreaterThanOrEqual) => match (&lhs.ty, &rhs.ty) {
+                // chained comparison: `0 < $x < 10` means `0 < $x and $x < 10`
+                _ if is_ordering_comparison(lhs) => (Type::Bool, None),
                 (Type::Int, Type::Int) => (Type::Bool, None),
                 (Type::Float, Type::Int) => (Type::Bool, None),
                 (Type::Int, Type::Float) => (Type::Bool, None),
@@ -815,6 +851,8 @@ pub fn math_result_type(
             Operator::Comparison(Comparison::In) => match (&lhs.ty, &rhs.ty) {
                 (t, Type::List(u)) if type_compatible(t, u) => (Type::Bool, None),
                 (Type::Int | Type::Float | Type::Number, Type::Range) => (Type::Bool, None),
+                // range in range is containment of the whole interval
+                (Type::Range, Type::Range) => (Type::Bool, None),
                 (Type::String, Type::String) => (Type::Bool, None),
                 (Type::String, Type::Record(_)) => (Type::Bool, None),
 
@@ -853,6 +891,7 @@ pub fn math_result_type(
             Operator::Comparison(Comparison::NotIn) => match (&lhs.ty, &rhs.ty) {
                 (t, Type::List(u)) if type_compatible(t, u) => (Type::Bool, None),
                 (Type::Int | Type::Float | Type::Number, Type::Range) => (Type::Bool, None),
+                (Type::Range, Type::Range) => (Type::Bool, None),
                 (Type::String, Type::String) => (Type::Bool, None),
                 (Type::String, Type::Record(_)) => (Type::Bool, None),
 
@@ -894,6 +933,11 @@ pub fn math_result_type(
             | Operator::Bits(Bits::BitXor)
             | Operator::Bits(Bits::BitAnd) => match (&lhs.ty, &rhs.ty) {
                 (Type::Int, Type::Int) => (Type::Int, None),
+                // range bit-and range is set intersection, which yields a
+                // narrower range or an empty list when they don't overlap
+                (Type::Range, Type::Range) if matches!(operator, Operator::Bits(Bits::BitAnd)) => {
+                    (Type::Any, None)
+                }
 
                 (Type::Any, _) => (Type::Any, None),
                 (_, Type::Any) => (Type::Any, None),
@@ -924,6 +968,9 @@ pub fn math_result_type(
                     )
                 }
             },
+            // A custom operator dispatches to a decl at runtime, so its result
+            // type is whatever that decl produces.
+            Operator::Custom(_) => (Type::Any, None),
             Operator::Assignment(_) => match (&lhs.ty, &rhs.ty) {
                 (x, y) if x == y => (Type::Nothing, None),
                 (Type::Any, _) => (Type::Nothing, None),
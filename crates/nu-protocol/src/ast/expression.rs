@@ -57,6 +57,10 @@ impl Expression {
                     Operator::Boolean(Boolean::Xor) => 45,
                     Operator::Boolean(Boolean::Or) => 40,
                     Operator::Assignment(_) => 10,
+                    // Custom operators bind like comparisons; without per-decl
+                    // precedence this keeps mixing them with the builtin
+                    // operators predictable.
+                    Operator::Custom(_) => 80,
                 }
             }
             _ => 0,
@@ -1,4 +1,4 @@
-use crate::{ShellError, Span};
+use crate::{DeclId, ShellError, Span};
 
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
@@ -66,6 +66,10 @@ pub enum Operator {
     Boolean(Boolean),
     Bits(Bits),
     Assignment(Assignment),
+    /// A user-defined infix operator: `$a <name> $b` dispatches to the decl
+    /// with the two operands as its positional arguments. The parser resolves
+    /// the bare name to a registered decl and tags the node with its id.
+    Custom(DeclId),
 }
 
 impl Display for Operator {
@@ -105,6 +109,8 @@ impl Display for Operator {
             Operator::Bits(Bits::BitAnd) => write!(f, "bit-and"),
             Operator::Bits(Bits::ShiftLeft) => write!(f, "bit-shl"),
             Operator::Bits(Bits::ShiftRight) => write!(f, "bit-shr"),
+            // the decl name is only known to the engine state
+            Operator::Custom(_) => write!(f, "<custom operator>"),
         }
     }
 }
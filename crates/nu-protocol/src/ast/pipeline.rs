@@ -7,6 +7,10 @@ pub enum Redirection {
     Stdout,
     Stderr,
     StdoutAndStderr,
+    /// `e>|`: pipe stderr into the next command instead of a file
+    StderrToPipe,
+    /// `o+e>|`: pipe combined stdout and stderr into the next command
+    StdoutAndStderrToPipe,
 }
 
 // Note: Span in the below is for the span of the connector not the whole element
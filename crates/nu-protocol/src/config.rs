@@ -84,7 +84,19 @@ pub struct Config {
     pub float_precision: i64,
     pub max_external_completion_results: i64,
     pub filesize_format: String,
+    /// Timezone (an offset like "+02:00", or "local") that datetime literals
+    /// written without an explicit offset are normalized to; empty keeps the
+    /// parsed offset. Literals with an offset and `into datetime --timezone`
+    /// are never affected.
+    pub datetime_literal_timezone: String,
+    /// Tolerance used by `==` and `!=` on two floats; values within this
+    /// epsilon of each other compare equal. 0.0 (the default) keeps exact
+    /// comparison, and non-float comparisons are never affected.
+    pub float_equality_epsilon: f64,
     pub use_ansi_coloring: bool,
+    /// When set, calling a custom command without one of its required
+    /// positional arguments is an error instead of binding `null`.
+    pub strict_positional_arguments: bool,
     pub quick_completions: bool,
     pub partial_completions: bool,
     pub completion_algorithm: String,
@@ -155,6 +167,8 @@ impl Default for Config {
 
             filesize_metric: false,
             filesize_format: "auto".into(),
+            datetime_literal_timezone: String::new(),
+            float_equality_epsilon: 0.0,
 
             cursor_shape_emacs: None,
             cursor_shape_vi_insert: None,
@@ -166,6 +180,7 @@ impl Default for Config {
             float_precision: 2,
             buffer_editor: Value::nothing(Span::unknown()),
             use_ansi_coloring: true,
+            strict_positional_arguments: false,
             bracketed_paste: true,
             edit_mode: "emacs".into(),
             shell_integration: false,
@@ -1166,6 +1181,35 @@ impl Value {
                     "use_ansi_coloring" => {
                         try_bool!(cols, vals, index, span, use_ansi_coloring);
                     }
+                    "strict_positional_arguments" => {
+                        try_bool!(cols, vals, index, span, strict_positional_arguments);
+                    }
+                    "datetime_literal_timezone" => {
+                        if let Ok(v) = value.as_string() {
+                            config.datetime_literal_timezone = v;
+                        } else {
+                            invalid!(Some(span), "should be a string");
+                            // Reconstruct
+                            vals[index] =
+                                Value::string(config.datetime_literal_timezone.clone(), span);
+                        }
+                    }
+                    "float_equality_epsilon" => {
+                        if let Ok(v) = value.as_float() {
+                            if v >= 0.0 {
+                                config.float_equality_epsilon = v;
+                            } else {
+                                invalid!(Some(span), "should be a non-negative float");
+                                // Reconstruct
+                                vals[index] =
+                                    Value::float(config.float_equality_epsilon, span);
+                            }
+                        } else {
+                            invalid!(Some(span), "should be a non-negative float");
+                            // Reconstruct
+                            vals[index] = Value::float(config.float_equality_epsilon, span);
+                        }
+                    }
                     "edit_mode" => {
                         if let Ok(v) = value.as_string() {
                             config.edit_mode = v.to_lowercase();
@@ -101,6 +101,25 @@ pub trait Command: Send + Sync + CommandClone {
         false
     }
 
+    // Whether the command has external side effects (writes files, spawns
+    // processes, ...); such commands are skipped in dry-run mode
+    fn is_destructive(&self) -> bool {
+        false
+    }
+
+    // Whether the command wants external-stream input as raw Value::Binary
+    // chunks instead of attempting UTF-8 string conversion
+    fn accepts_raw_binary_input(&self) -> bool {
+        false
+    }
+
+    // Whether the eval layer should coerce pipeline input to the declared
+    // input types of the signature before `run`, erroring on a mismatch.
+    // Opt-in, as commands declaring `Any` often do their own dispatch.
+    fn coerces_input(&self) -> bool {
+        false
+    }
+
     // If command is a block i.e. def blah [] { }, get the block id
     fn get_block_id(&self) -> Option<BlockId> {
         None
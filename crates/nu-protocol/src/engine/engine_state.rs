@@ -16,7 +16,7 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::sync::{
     atomic::{AtomicBool, AtomicU32},
-    Arc, Mutex,
+    Arc, Mutex, OnceLock,
 };
 
 pub static PWD_ENV: &str = "PWD";
@@ -93,6 +93,13 @@ pub struct EngineState {
     pub pipeline_externals_state: Arc<(AtomicU32, AtomicU32)>,
     pub repl_state: Arc<Mutex<ReplState>>,
     pub table_decl_id: Option<usize>,
+    // Lazily memoized decl ids for hot evaluation paths: `run-external` is
+    // looked up for every external invocation and `save` for every file
+    // redirection. Cloned engine states share the cache, so the first
+    // successful lookup is reused; redefining either command after startup is
+    // not picked up (the same holds for `table_decl_id` above).
+    run_external_decl_id: Arc<OnceLock<DeclId>>,
+    save_decl_id: Arc<OnceLock<DeclId>>,
     #[cfg(feature = "plugin")]
     pub plugin_signatures: Option<PathBuf>,
     #[cfg(not(windows))]
@@ -104,6 +111,19 @@ pub struct EngineState {
     pub regex_cache: Arc<Mutex<LruCache<String, Regex>>>,
     pub is_interactive: bool,
     pub is_login: bool,
+    /// When set (`nu --dry-run`), commands declaring themselves destructive
+    /// report what they would do instead of running.
+    pub dry_run: bool,
+    /// When set, a pressed ctrl-c makes `eval_call` raise
+    /// [`ShellError::InterruptedByUser`](crate::ShellError::InterruptedByUser)
+    /// instead of quietly returning nothing, so embedders can tell
+    /// cancellation apart from a command that legitimately returned nothing.
+    pub error_on_interrupt: bool,
+    /// When set, list, table and record literals and string interpolations are
+    /// capped at this many entries (bytes, for interpolations); exceeding it is
+    /// an evaluation error. Hosts embedding Nushell for untrusted scripts can
+    /// use this to bound memory; unset, nothing is enforced.
+    pub max_collection_size: Option<usize>,
     startup_time: i64,
 }
 
@@ -113,6 +133,9 @@ const REGEX_CACHE_SIZE: usize = 100; // must be nonzero, otherwise will panic
 pub const NU_VARIABLE_ID: usize = 0;
 pub const IN_VARIABLE_ID: usize = 1;
 pub const ENV_VARIABLE_ID: usize = 2;
+/// Bound to the current element index by iterating commands such as `each`.
+/// Only set in iteration contexts; see [`Stack::add_loop_index`](super::Stack::add_loop_index).
+pub const INDEX_VARIABLE_ID: usize = 3;
 // NOTE: If you add more to this list, make sure to update the > checks based on the last in the list
 
 impl EngineState {
@@ -150,6 +173,8 @@ impl EngineState {
                 cursor_pos: 0,
             })),
             table_decl_id: None,
+            run_external_decl_id: Arc::new(OnceLock::new()),
+            save_decl_id: Arc::new(OnceLock::new()),
             #[cfg(feature = "plugin")]
             plugin_signatures: None,
             #[cfg(not(windows))]
@@ -162,6 +187,9 @@ impl EngineState {
             ))),
             is_interactive: false,
             is_login: false,
+            dry_run: false,
+            error_on_interrupt: false,
+            max_collection_size: None,
             startup_time: -1,
         }
     }
@@ -596,6 +624,26 @@ impl EngineState {
         None
     }
 
+    /// Look up the `run-external` decl id, memoizing the first successful lookup.
+    pub fn find_run_external_decl(&self) -> Option<DeclId> {
+        if let Some(decl_id) = self.run_external_decl_id.get() {
+            return Some(*decl_id);
+        }
+        let decl_id = self.find_decl(b"run-external", &[])?;
+        let _ = self.run_external_decl_id.set(decl_id);
+        Some(decl_id)
+    }
+
+    /// Look up the `save` decl id, memoizing the first successful lookup.
+    pub fn find_save_decl(&self) -> Option<DeclId> {
+        if let Some(decl_id) = self.save_decl_id.get() {
+            return Some(*decl_id);
+        }
+        let decl_id = self.find_decl(b"save", &[])?;
+        let _ = self.save_decl_id.set(decl_id);
+        Some(decl_id)
+    }
+
     pub fn find_decl_name(&self, decl_id: DeclId, removed_overlays: &[Vec<u8>]) -> Option<&[u8]> {
         let mut visibility: Visibility = Visibility::new();
 
@@ -812,6 +860,15 @@ impl EngineState {
             .expect("internal error: missing block")
     }
 
+    /// The original source text of a block, when its span is known. The span
+    /// recorded by the parser covers the braces, so a closure's source comes
+    /// back as e.g. `{|| ... }`. Internal blocks without a span yield `None`.
+    pub fn get_block_source(&self, block_id: BlockId) -> Option<String> {
+        self.get_block(block_id)
+            .span
+            .map(|span| String::from_utf8_lossy(self.get_span_contents(span)).to_string())
+    }
+
     pub fn get_module(&self, module_id: ModuleId) -> &Module {
         self.modules
             .get(module_id)
@@ -1,12 +1,38 @@
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 use crate::engine::EngineState;
 use crate::engine::DEFAULT_OVERLAY_NAME;
-use crate::{ShellError, Span, Value, VarId};
+use crate::{DeclId, DidYouMean, ShellError, Span, Value, VarId};
 
 /// Environment variables per overlay
 pub type EnvVars = HashMap<String, HashMap<String, Value>>;
 
+/// Callback receiving the span of a pipeline element and a bounded preview of
+/// its output, invoked after each element while a block is evaluated with the
+/// hook installed on the [`Stack`].
+#[derive(Clone)]
+pub struct PipelineDebugHook(pub Arc<dyn Fn(Span, Value) + Send + Sync>);
+
+impl std::fmt::Debug for PipelineDebugHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PipelineDebugHook")
+    }
+}
+
+/// Callback receiving non-fatal runtime warnings as a message and the span of
+/// the offending code. Installing one on the [`Stack`] opts into strict
+/// pipeline checks: data that reaches the boundary between two pipelines in a
+/// block without being consumed is reported rather than silently dropped.
+#[derive(Clone)]
+pub struct WarningHook(pub Arc<dyn Fn(String, Span) + Send + Sync>);
+
+impl std::fmt::Debug for WarningHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("WarningHook")
+    }
+}
+
 /// A runtime value stack used during evaluation
 ///
 /// A note on implementation:
@@ -35,6 +61,16 @@ pub struct Stack {
     /// List of active overlays
     pub active_overlays: Vec<String>,
     pub recursion_count: Box<u64>,
+    /// Active custom-command call frames as (decl, evaluated positional args),
+    /// used to detect calls that repeat with identical arguments (cycles)
+    pub call_frames: Vec<(DeclId, Vec<Value>)>,
+    /// When set, receives a preview of each pipeline element's output during
+    /// block evaluation. `None` (the default) adds no overhead.
+    pub pipeline_debug_hook: Option<PipelineDebugHook>,
+    /// When set, receives warnings about pipeline results that are silently
+    /// discarded between pipelines. `None` (the default) keeps the usual
+    /// behavior of dropping the data without comment.
+    pub warning_hook: Option<WarningHook>,
 }
 
 impl Stack {
@@ -45,6 +81,9 @@ impl Stack {
             env_hidden: HashMap::new(),
             active_overlays: vec![DEFAULT_OVERLAY_NAME.to_string()],
             recursion_count: Box::new(0),
+            call_frames: vec![],
+            pipeline_debug_hook: None,
+            warning_hook: None,
         }
     }
 
@@ -70,7 +109,10 @@ impl Stack {
             }
         }
 
-        Err(ShellError::VariableNotFoundAtRuntime { span })
+        Err(ShellError::VariableNotFoundAtRuntime {
+            suggestion: DidYouMean::from(None),
+            span,
+        })
     }
 
     pub fn get_var_with_origin(&self, var_id: VarId, span: Span) -> Result<Value, ShellError> {
@@ -80,7 +122,10 @@ impl Stack {
             }
         }
 
-        Err(ShellError::VariableNotFoundAtRuntime { span })
+        Err(ShellError::VariableNotFoundAtRuntime {
+            suggestion: DidYouMean::from(None),
+            span,
+        })
     }
 
     pub fn add_var(&mut self, var_id: VarId, value: Value) {
@@ -94,6 +139,16 @@ impl Stack {
         self.vars.push((var_id, value));
     }
 
+    /// Bind `$index` to the current element index for this stack.
+    ///
+    /// Iterating commands (`each`, `par-each`, `filter`, ...) call this once per
+    /// element, before evaluating the closure, so the closure body can read the
+    /// position of the element it was given. `$index` is only set in iteration
+    /// contexts; referencing it anywhere else is a runtime error.
+    pub fn add_loop_index(&mut self, index: i64, span: Span) {
+        self.add_var(crate::engine::INDEX_VARIABLE_ID, Value::int(index, span));
+    }
+
     pub fn remove_var(&mut self, var_id: VarId) {
         for (idx, (id, _)) in self.vars.iter().enumerate() {
             if *id == var_id {
@@ -155,6 +210,9 @@ impl Stack {
             env_hidden: self.env_hidden.clone(),
             active_overlays: self.active_overlays.clone(),
             recursion_count: self.recursion_count.to_owned(),
+            call_frames: self.call_frames.clone(),
+            pipeline_debug_hook: self.pipeline_debug_hook.clone(),
+            warning_hook: self.warning_hook.clone(),
         }
     }
 
@@ -182,6 +240,9 @@ impl Stack {
             env_hidden: self.env_hidden.clone(),
             active_overlays: self.active_overlays.clone(),
             recursion_count: self.recursion_count.to_owned(),
+            call_frames: self.call_frames.clone(),
+            pipeline_debug_hook: self.pipeline_debug_hook.clone(),
+            warning_hook: self.warning_hook.clone(),
         }
     }
 
@@ -459,6 +459,8 @@ pub fn eval_constant(
                     }
                 }
                 Operator::Assignment(_) => Err(ShellError::NotAConstant(expr.span)),
+                // dispatches to a command at runtime
+                Operator::Custom(_) => Err(ShellError::NotAConstant(expr.span)),
             }
         }
         Expr::Block(block_id) => Ok(Value::block(*block_id, expr.span)),
@@ -106,7 +106,12 @@ pub enum ParseError {
     ),
 
     #[error("Capture of mutable variable.")]
-    #[diagnostic(code(nu::parser::expected_keyword))]
+    #[diagnostic(
+        code(nu::parser::capture_of_mutable_var),
+        help(
+            "closures capture variables by value when they are created, so mutating a captured variable could never be seen outside the closure. Build the new value with `reduce` or a `for` loop instead, or shadow the variable with `let` if you only need to read it."
+        )
+    )]
     CaptureOfMutableVar(#[label("capture of mutable variable")] Span),
 
     #[error("Expected keyword.")]
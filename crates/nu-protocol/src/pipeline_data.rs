@@ -62,6 +62,12 @@ pub struct PipelineMetadata {
 pub enum DataSource {
     Ls,
     HtmlThemes,
+    /// The value came from a filepath, directory or glob literal; the string
+    /// itself is an expanded path rather than arbitrary text.
+    FilePath,
+    /// Provenance stamped by the eval layer: the command that produced this
+    /// data, when the command did not attach more specific metadata itself.
+    Command { name: String, span: Span },
 }
 
 impl PipelineData {
@@ -2,7 +2,9 @@ use miette::Diagnostic;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::{ast::Operator, engine::StateWorkingSet, format_error, ParseError, Span, Value};
+use crate::{
+    ast::Operator, engine::StateWorkingSet, format_error, DidYouMean, ParseError, Span, Type, Value,
+};
 
 /// The fundamental error type for the evaluation engine. These cases represent different kinds of errors
 /// the evaluator might face, along with helpful spans to label. An error renderer will take this error value
@@ -104,6 +106,23 @@ pub enum ShellError {
         span: Span,
     },
 
+    /// An operator was applied to a value of a type it does not support.
+    ///
+    /// ## Resolution
+    ///
+    /// Convert the operand to the expected type first, or build it from an
+    /// expression that already produces that type.
+    #[error("The `{operator}` operator does not support `{found}` values.")]
+    #[diagnostic(code(nu::shell::operator_unsupported_type), help("{help}"))]
+    OperatorUnsupportedType {
+        operator: String,
+        expected: Type,
+        found: Type,
+        #[label = "expected `{expected}`, found `{found}`"]
+        span: Span,
+        help: String,
+    },
+
     /// A command received an argument with correct type but incorrect value.
     ///
     /// ## Resolution
@@ -322,7 +341,8 @@ pub enum ShellError {
     #[error("Variable not found")]
     #[diagnostic(code(nu::shell::variable_not_found))]
     VariableNotFoundAtRuntime {
-        #[label = "variable not found"]
+        suggestion: DidYouMean,
+        #[label = "variable not found. {suggestion}"]
         span: Span,
     },
 
@@ -620,6 +640,21 @@ pub enum ShellError {
         span: Span,
     },
 
+    /// An external command finished with a non-zero exit code.
+    ///
+    /// ## Resolution
+    ///
+    /// Check the exit code and the command's stderr output for the cause of the failure.
+    #[error("External command failed")]
+    #[diagnostic(code(nu::shell::external_command_failed))]
+    ExternalCommandFailed {
+        exit_code: i64,
+        /// The command's stderr output, when the stream was captured.
+        stderr: Option<String>,
+        #[label("exited with code {exit_code}")]
+        span: Span,
+    },
+
     /// An operation was attempted with an input unsupported for some reason.
     ///
     /// ## Resolution
@@ -1033,6 +1068,12 @@ pub enum ShellError {
     #[error("Return used outside of function")]
     Return(#[label = "used outside of function"] Span, Box<Value>),
 
+    /// Exit event, which unwinds the entire evaluation so the top-level runner
+    /// can terminate the process with the given exit code. Unlike `Return` it
+    /// is never converted back into a value, and it bypasses `try`/`catch`.
+    #[error("Exit")]
+    Exit(#[label = "exit requested here"] Span, i32),
+
     /// The code being executed called itself too many times.
     ///
     /// ## Resolution
@@ -1046,6 +1087,38 @@ pub enum ShellError {
         span: Option<Span>,
     },
 
+    /// A custom command keeps calling itself (possibly through other commands)
+    /// with the exact same arguments, so it can never terminate.
+    ///
+    /// ## Resolution
+    ///
+    /// Make sure the recursive call changes its arguments, or add a base case
+    /// that stops the recursion. The cycle lists the commands involved.
+    #[error("Infinite recursion detected through: {cycle}")]
+    #[diagnostic(code(nu::shell::infinite_recursion))]
+    InfiniteRecursion {
+        cycle: String,
+        #[label("this call repeats with identical arguments")]
+        span: Span,
+    },
+
+    /// An inner error was raised while a command was running. The original
+    /// error is carried along as the cause.
+    ///
+    /// ## Resolution
+    ///
+    /// Inspect the related error below for the actual problem; this variant
+    /// only adds the name and location of the command that was running.
+    #[error("Error while running `{command}`")]
+    #[diagnostic(code(nu::shell::error_while_running_command))]
+    WhileRunningCommand {
+        command: String,
+        #[label("while running this command")]
+        span: Span,
+        #[related]
+        inner: Vec<ShellError>,
+    },
+
     /// An attempt to access a record column failed.
     #[error("Access failure: {message}")]
     #[diagnostic(code(nu::shell::lazy_record_access_failed))]
@@ -1157,6 +1230,25 @@ This is an internal Nushell error, please file an issue https://github.com/nushe
 
 // TODO: Implement as From trait
 impl ShellError {
+    /// Wrap this error with the name and span of the command that was running
+    /// when it was raised. Control-flow "errors" (`Break`, `Continue`,
+    /// `Return`, `Exit`) pass through untouched, as does an error already
+    /// carrying command context, so chains stay one level deep.
+    pub fn wrap_with_command_context(self, command: &str, span: Span) -> ShellError {
+        match self {
+            ShellError::Break(_)
+            | ShellError::Continue(_)
+            | ShellError::Return(_, _)
+            | ShellError::Exit(_, _)
+            | ShellError::WhileRunningCommand { .. } => self,
+            inner => ShellError::WhileRunningCommand {
+                command: command.into(),
+                span,
+                inner: vec![inner],
+            },
+        }
+    }
+
     pub fn wrap(self, working_set: &StateWorkingSet, span: Span) -> ParseError {
         let msg = format_error(working_set, &self);
         ParseError::LabeledError(
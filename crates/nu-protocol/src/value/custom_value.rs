@@ -52,4 +52,17 @@ pub trait CustomValue: fmt::Debug + Send + Sync {
     ) -> Result<Value, ShellError> {
         Err(ShellError::UnsupportedOperator { operator, span: op })
     }
+
+    // Definition of an operation between a base value on the left and this
+    // custom value on the right (e.g. `1 + $custom`). Implement this for
+    // operators that aren't commutative if mixed operands should work.
+    fn operation_rhs(
+        &self,
+        _lhs_span: Span,
+        operator: Operator,
+        op: Span,
+        _left: &Value,
+    ) -> Result<Value, ShellError> {
+        Err(ShellError::UnsupportedOperator { operator, span: op })
+    }
 }
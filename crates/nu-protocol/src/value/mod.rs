@@ -2411,6 +2411,41 @@ impl PartialEq for Value {
     }
 }
 
+/// The arithmetic coercion table for additive operators.
+///
+/// When a plain `int` is mixed with a unit-carrying type, the int is
+/// reinterpreted in the unit of the other operand and the result keeps the
+/// unit-carrying type:
+///
+/// | lhs      | rhs      | treated as            |
+/// |----------|----------|-----------------------|
+/// | int      | filesize | filesize + filesize   |
+/// | filesize | int      | filesize + filesize   |
+/// | int      | duration | duration + duration   |
+/// | duration | int      | duration + duration   |
+///
+/// Multiplication and division scale unit types by plain numbers in their own
+/// match arms and do not consult this table. Combinations not listed here are
+/// left untouched, so the caller falls through to its usual arms (and
+/// ultimately to an `OperatorMismatch` error carrying both operand spans).
+fn coerce_unit_operands(lhs: &Value, rhs: &Value) -> (Value, Value) {
+    match (lhs, rhs) {
+        (Value::Int { val, .. }, Value::Filesize { .. }) => {
+            (Value::filesize(*val, lhs.span()), rhs.clone())
+        }
+        (Value::Filesize { .. }, Value::Int { val, .. }) => {
+            (lhs.clone(), Value::filesize(*val, rhs.span()))
+        }
+        (Value::Int { val, .. }, Value::Duration { .. }) => {
+            (Value::duration(*val, lhs.span()), rhs.clone())
+        }
+        (Value::Duration { .. }, Value::Int { val, .. }) => {
+            (lhs.clone(), Value::duration(*val, rhs.span()))
+        }
+        _ => (lhs.clone(), rhs.clone()),
+    }
+}
+
 impl Value {
     pub fn add(&self, op: Span, rhs: &Value, span: Span) -> Result<Value, ShellError> {
         match (self, rhs) {
@@ -2468,9 +2503,18 @@ impl Value {
                 }
             }
 
+            (Value::Int { .. }, Value::Filesize { .. } | Value::Duration { .. })
+            | (Value::Filesize { .. } | Value::Duration { .. }, Value::Int { .. }) => {
+                let (lhs, rhs) = coerce_unit_operands(self, rhs);
+                lhs.add(op, &rhs, span)
+            }
+
             (Value::CustomValue { val: lhs, .. }, rhs) => {
                 lhs.operation(self.span(), Operator::Math(Math::Plus), op, rhs)
             }
+            (lhs, Value::CustomValue { val: rhs, .. }) => {
+                rhs.operation_rhs(self.span(), Operator::Math(Math::Plus), op, lhs)
+            }
 
             _ => Err(ShellError::OperatorMismatch {
                 op_span: op,
@@ -2582,9 +2626,18 @@ impl Value {
                 }
             }
 
+            (Value::Int { .. }, Value::Filesize { .. } | Value::Duration { .. })
+            | (Value::Filesize { .. } | Value::Duration { .. }, Value::Int { .. }) => {
+                let (lhs, rhs) = coerce_unit_operands(self, rhs);
+                lhs.sub(op, &rhs, span)
+            }
+
             (Value::CustomValue { val: lhs, .. }, rhs) => {
                 lhs.operation(self.span(), Operator::Math(Math::Minus), op, rhs)
             }
+            (lhs, Value::CustomValue { val: rhs, .. }) => {
+                rhs.operation_rhs(self.span(), Operator::Math(Math::Minus), op, lhs)
+            }
 
             _ => Err(ShellError::OperatorMismatch {
                 op_span: op,
@@ -2641,6 +2694,9 @@ impl Value {
             (Value::CustomValue { val: lhs, .. }, rhs) => {
                 lhs.operation(self.span(), Operator::Math(Math::Multiply), op, rhs)
             }
+            (lhs, Value::CustomValue { val: rhs, .. }) => {
+                rhs.operation_rhs(self.span(), Operator::Math(Math::Multiply), op, lhs)
+            }
             _ => Err(ShellError::OperatorMismatch {
                 op_span: op,
                 lhs_ty: self.get_type().to_string(),
@@ -2744,6 +2800,9 @@ impl Value {
             (Value::CustomValue { val: lhs, .. }, rhs) => {
                 lhs.operation(self.span(), Operator::Math(Math::Divide), op, rhs)
             }
+            (lhs, Value::CustomValue { val: rhs, .. }) => {
+                rhs.operation_rhs(self.span(), Operator::Math(Math::Divide), op, lhs)
+            }
 
             _ => Err(ShellError::OperatorMismatch {
                 op_span: op,
@@ -2880,6 +2939,9 @@ impl Value {
             (Value::CustomValue { val: lhs, .. }, rhs) => {
                 lhs.operation(self.span(), Operator::Math(Math::Divide), op, rhs)
             }
+            (lhs, Value::CustomValue { val: rhs, .. }) => {
+                rhs.operation_rhs(self.span(), Operator::Math(Math::Divide), op, lhs)
+            }
 
             _ => Err(ShellError::OperatorMismatch {
                 op_span: op,
@@ -2901,6 +2963,15 @@ impl Value {
             );
         }
 
+        if let Value::CustomValue { val: rhs, .. } = rhs {
+            return rhs.operation_rhs(
+                self.span(),
+                Operator::Comparison(Comparison::LessThan),
+                op,
+                self,
+            );
+        }
+
         if matches!(self, Value::Nothing { .. }) || matches!(rhs, Value::Nothing { .. }) {
             return Ok(Value::nothing(span));
         }
@@ -2941,6 +3012,15 @@ impl Value {
             );
         }
 
+        if let Value::CustomValue { val: rhs, .. } = rhs {
+            return rhs.operation_rhs(
+                self.span(),
+                Operator::Comparison(Comparison::LessThanOrEqual),
+                op,
+                self,
+            );
+        }
+
         if matches!(self, Value::Nothing { .. }) || matches!(rhs, Value::Nothing { .. }) {
             return Ok(Value::nothing(span));
         }
@@ -2979,6 +3059,15 @@ impl Value {
             );
         }
 
+        if let Value::CustomValue { val: rhs, .. } = rhs {
+            return rhs.operation_rhs(
+                self.span(),
+                Operator::Comparison(Comparison::GreaterThan),
+                op,
+                self,
+            );
+        }
+
         if matches!(self, Value::Nothing { .. }) || matches!(rhs, Value::Nothing { .. }) {
             return Ok(Value::nothing(span));
         }
@@ -3017,6 +3106,15 @@ impl Value {
             );
         }
 
+        if let Value::CustomValue { val: rhs, .. } = rhs {
+            return rhs.operation_rhs(
+                self.span(),
+                Operator::Comparison(Comparison::GreaterThanOrEqual),
+                op,
+                self,
+            );
+        }
+
         if matches!(self, Value::Nothing { .. }) || matches!(rhs, Value::Nothing { .. }) {
             return Ok(Value::nothing(span));
         }
@@ -3059,6 +3157,15 @@ impl Value {
             );
         }
 
+        if let Value::CustomValue { val: rhs, .. } = rhs {
+            return rhs.operation_rhs(
+                self.span(),
+                Operator::Comparison(Comparison::Equal),
+                op,
+                self,
+            );
+        }
+
         if let Some(ordering) = self.partial_cmp(rhs) {
             Ok(Value::bool(matches!(ordering, Ordering::Equal), span))
         } else {
@@ -3087,6 +3194,15 @@ impl Value {
             );
         }
 
+        if let Value::CustomValue { val: rhs, .. } = rhs {
+            return rhs.operation_rhs(
+                self.span(),
+                Operator::Comparison(Comparison::NotEqual),
+                op,
+                self,
+            );
+        }
+
         if let Some(ordering) = self.partial_cmp(rhs) {
             Ok(Value::bool(!matches!(ordering, Ordering::Equal), span))
         } else {
@@ -3105,8 +3221,45 @@ impl Value {
         }
     }
 
+    /// Like [`Value::eq`], but two floats compare equal when they are within
+    /// `epsilon` of each other. Only float-float comparisons are affected; all
+    /// other type combinations defer to the exact comparison.
+    pub fn approx_eq(
+        &self,
+        op: Span,
+        rhs: &Value,
+        span: Span,
+        epsilon: f64,
+    ) -> Result<Value, ShellError> {
+        match (self, rhs) {
+            (Value::Float { val: lhs, .. }, Value::Float { val: rhs, .. }) => {
+                Ok(Value::bool((lhs - rhs).abs() <= epsilon, span))
+            }
+            _ => self.eq(op, rhs, span),
+        }
+    }
+
+    /// The negation of [`Value::approx_eq`]; see there for the epsilon rules.
+    pub fn approx_ne(
+        &self,
+        op: Span,
+        rhs: &Value,
+        span: Span,
+        epsilon: f64,
+    ) -> Result<Value, ShellError> {
+        match (self, rhs) {
+            (Value::Float { val: lhs, .. }, Value::Float { val: rhs, .. }) => {
+                Ok(Value::bool((lhs - rhs).abs() > epsilon, span))
+            }
+            _ => self.ne(op, rhs, span),
+        }
+    }
+
     pub fn r#in(&self, op: Span, rhs: &Value, span: Span) -> Result<Value, ShellError> {
         match (self, rhs) {
+            (Value::Range { val: lhs, .. }, Value::Range { val: rhs, .. }) => {
+                Ok(Value::bool(rhs.contains_range(lhs), span))
+            }
             (lhs, Value::Range { val: rhs, .. }) => Ok(Value::bool(rhs.contains(lhs), span)),
             (Value::String { val: lhs, .. }, Value::String { val: rhs, .. }) => {
                 Ok(Value::bool(rhs.contains(lhs), span))
@@ -3155,6 +3308,9 @@ impl Value {
 
     pub fn not_in(&self, op: Span, rhs: &Value, span: Span) -> Result<Value, ShellError> {
         match (self, rhs) {
+            (Value::Range { val: lhs, .. }, Value::Range { val: rhs, .. }) => {
+                Ok(Value::bool(!rhs.contains_range(lhs), span))
+            }
             (lhs, Value::Range { val: rhs, .. }) => Ok(Value::bool(!rhs.contains(lhs), span)),
             (Value::String { val: lhs, .. }, Value::String { val: rhs, .. }) => {
                 Ok(Value::bool(!rhs.contains(lhs), span))
@@ -3394,6 +3550,14 @@ impl Value {
             (Value::Int { val: lhs, .. }, Value::Int { val: rhs, .. }) => {
                 Ok(Value::int(*lhs & rhs, span))
             }
+            // on ranges, `bit-and` is set intersection: the narrower range
+            // both operands cover, or an empty list when they don't overlap
+            (Value::Range { val: lhs, .. }, Value::Range { val: rhs, .. }) => {
+                match lhs.intersect(rhs, op)? {
+                    Some(range) => Ok(Value::range(range, span)),
+                    None => Ok(Value::list(vec![], span)),
+                }
+            }
             (Value::CustomValue { val: lhs, .. }, rhs) => {
                 lhs.operation(span, Operator::Bits(Bits::BitAnd), op, rhs)
             }
@@ -3447,6 +3611,9 @@ impl Value {
             (Value::CustomValue { val: lhs, .. }, rhs) => {
                 lhs.operation(span, Operator::Math(Math::Modulo), op, rhs)
             }
+            (lhs, Value::CustomValue { val: rhs, .. }) => {
+                rhs.operation_rhs(span, Operator::Math(Math::Modulo), op, lhs)
+            }
 
             _ => Err(ShellError::OperatorMismatch {
                 op_span: op,
@@ -3533,6 +3700,9 @@ impl Value {
             (Value::CustomValue { val: lhs, .. }, rhs) => {
                 lhs.operation(span, Operator::Math(Math::Pow), op, rhs)
             }
+            (lhs, Value::CustomValue { val: rhs, .. }) => {
+                rhs.operation_rhs(span, Operator::Math(Math::Pow), op, lhs)
+            }
 
             _ => Err(ShellError::OperatorMismatch {
                 op_span: op,
@@ -104,6 +104,19 @@ impl Range {
         matches!(self.inclusion, RangeInclusion::Inclusive)
     }
 
+    /// Whether the range is open-ended (`1..`), i.e. its endpoint was filled in
+    /// with the integer min/max placeholder. Such ranges can only be consumed
+    /// lazily; materializing them would iterate forever.
+    pub fn is_unbounded(&self) -> bool {
+        matches!(
+            self.to,
+            Value::Int {
+                val: i64::MAX | i64::MIN,
+                ..
+            }
+        )
+    }
+
     pub fn from(&self) -> Result<i64, ShellError> {
         self.from.as_int()
     }
@@ -126,6 +139,86 @@ impl Range {
         }
     }
 
+    /// Whether `other` lies entirely inside this range. Only the spanned
+    /// interval matters — increments play no part — and a shared endpoint is
+    /// contained unless this range excludes it while `other` includes it.
+    pub fn contains_range(&self, other: &Range) -> bool {
+        let (s_lo, s_lo_inc, s_hi, s_hi_inc) = self.bounds();
+        let (o_lo, o_lo_inc, o_hi, o_hi_inc) = other.bounds();
+
+        let lo_ok = match o_lo.partial_cmp(s_lo) {
+            Some(Ordering::Greater) => true,
+            Some(Ordering::Equal) => s_lo_inc || !o_lo_inc,
+            _ => false,
+        };
+        let hi_ok = match o_hi.partial_cmp(s_hi) {
+            Some(Ordering::Less) => true,
+            Some(Ordering::Equal) => s_hi_inc || !o_hi_inc,
+            _ => false,
+        };
+
+        lo_ok && hi_ok
+    }
+
+    /// Low and high bounds with their inclusivity, regardless of whether the
+    /// range counts up or down.
+    fn bounds(&self) -> (&Value, bool, &Value, bool) {
+        if self.moves_up() {
+            (&self.from, true, &self.to, self.is_end_inclusive())
+        } else {
+            (&self.to, self.is_end_inclusive(), &self.from, true)
+        }
+    }
+
+    /// Set-intersect two ranges, producing the narrower range both cover, or
+    /// `None` when they don't overlap. Only ranges that count up are
+    /// supported; the result keeps `self`'s increment, and a shared endpoint
+    /// stays inclusive only when both sides include it.
+    pub fn intersect(&self, other: &Range, op: Span) -> Result<Option<Range>, ShellError> {
+        if !self.moves_up() || !other.moves_up() {
+            let offending = if self.moves_up() { other } else { self };
+            return Err(ShellError::IncorrectValue {
+                msg: "range intersection requires ranges that count up".into(),
+                val_span: offending.from.span(),
+                call_span: op,
+            });
+        }
+
+        let from = if self.from.partial_cmp(&other.from) == Some(Ordering::Less) {
+            other.from.clone()
+        } else {
+            self.from.clone()
+        };
+        let (to, inclusion) = match self.to.partial_cmp(&other.to) {
+            Some(Ordering::Less) => (self.to.clone(), self.inclusion),
+            Some(Ordering::Greater) => (other.to.clone(), other.inclusion),
+            _ => (
+                self.to.clone(),
+                if self.is_end_inclusive() && other.is_end_inclusive() {
+                    RangeInclusion::Inclusive
+                } else {
+                    RangeInclusion::RightExclusive
+                },
+            ),
+        };
+
+        let empty = match from.partial_cmp(&to) {
+            Some(Ordering::Less) => false,
+            Some(Ordering::Equal) => matches!(inclusion, RangeInclusion::RightExclusive),
+            _ => true,
+        };
+        if empty {
+            return Ok(None);
+        }
+
+        Ok(Some(Range {
+            from,
+            incr: self.incr.clone(),
+            to,
+            inclusion,
+        }))
+    }
+
     pub fn into_range_iter(
         self,
         ctrlc: Option<Arc<AtomicBool>>,
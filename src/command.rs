@@ -99,6 +99,7 @@ pub(crate) fn parse_commandline_args(
             let plugin_file: Option<Expression> = call.get_flag_expr("plugin-config");
             let no_config_file = call.get_named_arg("no-config-file");
             let no_std_lib = call.get_named_arg("no-std-lib");
+            let dry_run = call.get_named_arg("dry-run");
             let config_file: Option<Expression> = call.get_flag_expr("config");
             let env_file: Option<Expression> = call.get_flag_expr("env-config");
             let log_level: Option<Expression> = call.get_flag_expr("log-level");
@@ -184,6 +185,7 @@ pub(crate) fn parse_commandline_args(
                 plugin_file,
                 no_config_file,
                 no_std_lib,
+                dry_run,
                 config_file,
                 env_file,
                 log_level,
@@ -222,6 +224,7 @@ pub(crate) struct NushellCliArgs {
     pub(crate) plugin_file: Option<Spanned<String>>,
     pub(crate) no_config_file: Option<Spanned<String>>,
     pub(crate) no_std_lib: Option<Spanned<String>>,
+    pub(crate) dry_run: Option<Spanned<String>>,
     pub(crate) config_file: Option<Spanned<String>>,
     pub(crate) env_file: Option<Spanned<String>>,
     pub(crate) log_level: Option<Spanned<String>>,
@@ -279,6 +282,11 @@ impl Command for Nu {
                 Some('n'),
             )
             .switch("no-std-lib", "start with no standard library", None)
+            .switch(
+                "dry-run",
+                "do not run destructive commands; print what they would do instead",
+                None,
+            )
             .named(
                 "threads",
                 SyntaxShape::Int,
@@ -91,6 +91,8 @@ fn main() -> Result<()> {
 
     engine_state.is_login = parsed_nu_cli_args.login_shell.is_some();
 
+    engine_state.dry_run = parsed_nu_cli_args.dry_run.is_some();
+
     let use_color = engine_state.get_config().use_ansi_coloring;
     if let Some(level) = parsed_nu_cli_args
         .log_level
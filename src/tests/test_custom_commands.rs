@@ -140,5 +140,23 @@ fn infinite_recursion_does_not_panic() {
     let actual = nu!(r#"
             def bang [] { bang }; bang
         "#);
+    assert!(actual.err.contains("Infinite recursion detected through: bang"));
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn recursion_progressing_through_a_flag_is_not_flagged_as_infinite() {
+    let actual = nu!(r#"
+            def countdown [--n: int] { if $n > 0 { countdown --n ($n - 1) } else { "done" } }; countdown --n 15
+        "#);
+    assert_eq!(actual.out, "done");
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn progressing_recursion_past_limit_keeps_depth_error() {
+    let actual = nu!(r#"
+            def climb [n] { climb ($n + 1) }; climb 0
+        "#);
     assert!(actual.err.contains("Recursion limit (50) reached"));
 }
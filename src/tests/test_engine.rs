@@ -440,3 +440,209 @@ fn short_flags_2() -> TestResult {
         "11",
     )
 }
+
+#[test]
+fn mutate_list_element_field() -> TestResult {
+    run_test(r#"mut xs = [{a: 1}]; $xs.0.a = 2; $xs.0.a"#, "2")
+}
+
+#[test]
+fn mutate_nested_list_element() -> TestResult {
+    run_test(
+        r#"mut t = [[a]; [[1, 2]]]; $t.0.a.1 = 5; $t.0.a.1"#,
+        "5",
+    )
+}
+
+#[test]
+fn mutate_list_element_out_of_bounds() -> TestResult {
+    fail_test(r#"mut xs = [{a: 1}]; $xs.5.a = 2"#, "wrong row number")
+}
+
+#[test]
+fn closure_variable_as_pipeline_stage() -> TestResult {
+    run_test(
+        r#"let double_all = {|| each {|x| $x * 2 } }; [1 2 3] | $double_all | math sum"#,
+        "12",
+    )
+}
+
+#[test]
+fn closure_in_record_as_pipeline_stage() -> TestResult {
+    run_test(
+        r#"let fns = {sum: {|| math sum }}; [1 2 3] | $fns.sum"#,
+        "6",
+    )
+}
+
+#[test]
+fn closure_variable_without_input_is_a_value() -> TestResult {
+    run_test(r#"let c = {|| 1 }; $c | describe"#, "closure")
+}
+
+#[test]
+fn closure_reads_env_live() -> TestResult {
+    run_test(
+        r#"$env.FOO = 'a'; let c = {|| $env.FOO }; $env.FOO = 'b'; do $c"#,
+        "b",
+    )
+}
+
+#[test]
+fn return_single_value_stays_scalar() -> TestResult {
+    run_test(r#"def foo [] { return 3 }; foo | describe"#, "int")
+}
+
+#[test]
+fn return_multiple_values_becomes_list() -> TestResult {
+    run_test(r#"def foo [] { return 1 2 3 }; foo | math sum"#, "6")
+}
+
+#[test]
+fn return_no_value_is_nothing() -> TestResult {
+    run_test(r#"def foo [] { return }; foo | describe"#, "nothing")
+}
+
+#[test]
+fn in_variable_reused_within_one_element() -> TestResult {
+    run_test(
+        "1..3 | each {|it| $it } | (($in | math sum) + ($in | math sum))",
+        "12",
+    )
+}
+
+#[test]
+fn unary_not_type_error_suggests_into_bool() -> TestResult {
+    fail_test("not 1", "into bool")
+}
+
+#[test]
+fn unary_not_type_error_names_found_type() -> TestResult {
+    fail_test(r#"not "yes""#, "string")
+}
+
+#[test]
+fn index_variable_in_each() -> TestResult {
+    run_test("[10 20 30] | each { $index } | math sum", "3")
+}
+
+#[test]
+fn index_variable_in_filter() -> TestResult {
+    run_test("[10 20 30] | filter { $index > 0 } | math sum", "50")
+}
+
+#[test]
+fn index_variable_outside_iteration_is_an_error() -> TestResult {
+    fail_test("$index", "not found")
+}
+
+#[test]
+fn index_variable_cannot_be_shadowed() -> TestResult {
+    fail_test("let index = 1", "builtin")
+}
+
+#[test]
+fn env_cell_path_append_assign() -> TestResult {
+    run_test(
+        r#"$env.PATH = ['/bin']; $env.PATH ++= ['/x']; $env.PATH | str join ':'"#,
+        "/bin:/x",
+    )
+}
+
+#[test]
+fn env_cell_path_plus_assign() -> TestResult {
+    run_test(r#"$env.COUNT = 1; $env.COUNT += 1; $env.COUNT"#, "2")
+}
+
+#[test]
+fn env_nested_cell_path_compound_assign() -> TestResult {
+    run_test(r#"$env.FOO = {n: 1}; $env.FOO.n *= 5; $env.FOO.n"#, "5")
+}
+
+#[test]
+fn untaken_if_branch_is_not_evaluated() -> TestResult {
+    // the division by zero would error at runtime if the branch were built
+    run_test(r#"if true { 1 } else { 1 / 0 }"#, "1")
+}
+
+#[test]
+fn untaken_if_branch_side_effects_do_not_run() -> TestResult {
+    run_test(
+        r#"$env.HIT = 'no'; if true { 'a' } else { $env.HIT = 'yes'; 'b' } | ignore; $env.HIT"#,
+        "no",
+    )
+}
+
+#[test]
+fn untaken_if_branch_literal_is_not_built() -> TestResult {
+    // a literal list in the untaken branch must not be constructed
+    run_test(r#"if false { [1 (1 / 0) 3] } else { [] } | length"#, "0")
+}
+
+#[test]
+fn in_variable_in_custom_command_first_pipeline() -> TestResult {
+    run_test(r#"def upper [] { $in | str upcase }; 'abc' | upper"#, "ABC")
+}
+
+#[test]
+fn in_variable_in_custom_command_later_pipeline() -> TestResult {
+    run_test(
+        r#"def second [] { null; $in | math sum }; [1 2 3] | second"#,
+        "6",
+    )
+}
+
+#[test]
+fn in_variable_in_custom_command_prefers_own_input() -> TestResult {
+    // the callee's own input shadows any $in captured from the caller
+    run_test(r#"def own [] { $in }; 'outer' | do { 'inner' | own }"#, "inner")
+}
+
+#[test]
+fn automatic_env_var_cannot_be_set_directly() -> TestResult {
+    fail_test(r#"$env.PWD = 'foo'"#, "cannot be set manually")
+}
+
+#[test]
+fn automatic_env_var_cannot_be_set_through_nesting() -> TestResult {
+    fail_test(r#"$env.PWD.extra = 'foo'"#, "cannot be set manually")
+}
+
+#[test]
+fn automatic_file_pwd_cannot_be_set_through_nesting() -> TestResult {
+    fail_test(r#"$env.FILE_PWD.extra = 'foo'"#, "cannot be set manually")
+}
+
+#[test]
+fn command_output_is_stamped_with_provenance() -> TestResult {
+    run_test(r#"[1 2] | each {|x| $x } | metadata | get source"#, "each")
+}
+
+#[test]
+fn provenance_does_not_overwrite_specific_metadata() -> TestResult {
+    run_test(r#"ls | metadata | get source"#, "ls")
+}
+
+#[test]
+fn in_variable_in_if_condition_non_empty_input() -> TestResult {
+    run_test(
+        r#"[1 2 3] | if ($in | is-empty) { 'empty' } else { 'full' }"#,
+        "full",
+    )
+}
+
+#[test]
+fn in_variable_in_if_condition_empty_input() -> TestResult {
+    run_test(
+        r#"[] | if ($in | is-empty) { 'empty' } else { 'full' }"#,
+        "empty",
+    )
+}
+
+#[test]
+fn in_variable_in_if_condition_keeps_input_for_branch() -> TestResult {
+    run_test(
+        r#"[1 2 3] | if ($in | length) > 2 { math sum } else { 0 }"#,
+        "6",
+    )
+}
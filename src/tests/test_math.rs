@@ -186,3 +186,83 @@ fn gte_null() -> TestResult {
     run_test("null >= 3 | to nuon", "null").unwrap();
     run_test("null >= null | to nuon", "null")
 }
+
+#[test]
+fn chained_comparison() -> TestResult {
+    run_test("let x = 5; 0 < $x < 10", "true")
+}
+
+#[test]
+fn chained_comparison_false() -> TestResult {
+    run_test("let x = 15; 0 < $x < 10", "false")
+}
+
+#[test]
+fn chained_comparison_mixed_operators() -> TestResult {
+    run_test("1 <= 1 < 2", "true")
+}
+
+#[test]
+fn add_int_and_filesize() -> TestResult {
+    run_test("(1 + 1kb) == 1001b", "true").unwrap();
+    run_test("(1kb + 1) == 1001b", "true")
+}
+
+#[test]
+fn sub_int_and_duration() -> TestResult {
+    run_test("(1sec - 1) == 999999999ns", "true").unwrap();
+    run_test("(2000000000 - 1sec) == 1sec", "true")
+}
+
+#[test]
+fn float_equality_is_exact_by_default() -> TestResult {
+    run_test("0.1 + 0.2 == 0.3", "false")
+}
+
+#[test]
+fn float_equality_epsilon() -> TestResult {
+    run_test(
+        "$env.config = {float_equality_epsilon: 0.000000001}; 0.1 + 0.2 == 0.3",
+        "true",
+    )
+    .unwrap();
+    run_test(
+        "$env.config = {float_equality_epsilon: 0.000000001}; 0.1 + 0.2 != 0.3",
+        "false",
+    )
+}
+
+#[test]
+fn range_in_range_containment() -> TestResult {
+    run_test(r#"(2..4) in (1..10)"#, "true")
+}
+
+#[test]
+fn range_in_range_outside() -> TestResult {
+    run_test(r#"(2..12) in (1..10)"#, "false")
+}
+
+#[test]
+fn range_not_in_range() -> TestResult {
+    run_test(r#"(2..12) not-in (1..10)"#, "true")
+}
+
+#[test]
+fn range_exclusive_end_containment() -> TestResult {
+    run_test(r#"(5..10) in (1..<10)"#, "false")
+}
+
+#[test]
+fn range_intersection() -> TestResult {
+    run_test(r#"((1..10) bit-and (5..20)) == 5..10"#, "true")
+}
+
+#[test]
+fn range_intersection_empty_is_list() -> TestResult {
+    run_test(r#"(1..3) bit-and (8..10) | length"#, "0")
+}
+
+#[test]
+fn range_equality() -> TestResult {
+    run_test(r#"0..5 == 0..5"#, "true")
+}
@@ -763,3 +763,18 @@ fn properly_typecheck_rest_param() -> TestResult {
 fn implied_collect_has_compatible_type() -> TestResult {
     run_test(r#"let idx = 3 | $in; $idx < 1"#, "false")
 }
+
+#[test]
+fn mutable_var_capture_in_closure_is_a_parse_error() -> TestResult {
+    // closures capture by value, so mutation inside could never be observed
+    fail_test(
+        "mut x = 0; [1 2] | each { $x += 1 }",
+        "capture of mutable variable",
+    )
+}
+
+#[test]
+fn mutable_var_mutation_in_loop_still_works() -> TestResult {
+    // `for` bodies are blocks, not closures, so mutation is fine there
+    run_test("mut x = 0; for i in [1 2] { $x += $i }; $x", "3")
+}
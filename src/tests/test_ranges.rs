@@ -34,3 +34,13 @@ fn range_and_reduction() -> TestResult {
 fn zip_ranges() -> TestResult {
     run_test(r#"1..3 | zip 4..6 | get 2.1"#, "6")
 }
+
+#[test]
+fn infinite_range_each_is_lazy() -> TestResult {
+    run_test(r#"1.. | each {|i| $i * 2 } | first 3 | math sum"#, "12")
+}
+
+#[test]
+fn infinite_range_take_is_lazy() -> TestResult {
+    run_test(r#"0.. | take 5 | math sum"#, "10")
+}
@@ -71,3 +71,41 @@ fn case_insensitive_sort_columns() -> TestResult {
         r#"[{"version": "four","package": "abc"},{"version": "three","package": "abc"},{"version": "two","package": "Abc"}]"#,
     )
 }
+
+#[test]
+fn interpolation_format_spec_precision() -> TestResult {
+    run_test(r#"$"(:.2f)(3.14159)""#, "3.14")
+}
+
+#[test]
+fn interpolation_format_spec_padding() -> TestResult {
+    run_test(r#"$"(:05d)(42)""#, "00042")
+}
+
+#[test]
+fn interpolation_format_spec_base() -> TestResult {
+    run_test(r#"$"(:x)(255)""#, "ff")
+}
+
+#[test]
+fn interpolation_format_spec_fallback() -> TestResult {
+    run_test(r#"$"(:.2f)('foo')""#, "foo")
+}
+
+#[test]
+fn interpolation_lone_spec_like_part_is_an_expression() -> TestResult {
+    run_test(r#"def x [] { "ran" }; $"(x)""#, "ran")
+}
+
+#[test]
+fn interpolation_spec_like_part_before_text_is_an_expression() -> TestResult {
+    run_test(r#"def b [] { "called" }; $"(b) it""#, "called it")
+}
+
+#[test]
+fn interpolation_adjacent_command_calls_are_not_specs() -> TestResult {
+    run_test(
+        r#"def x [] { "first" }; def y [] { "second" }; $"(x)(y)""#,
+        "firstsecond",
+    )
+}
@@ -329,3 +329,16 @@ fn main_script_can_have_subcommands2() {
         assert!(actual.out.contains("usage: script.nu"));
     })
 }
+
+#[test]
+fn exit_unwinds_out_of_functions() {
+    let actual = nu!("def foo [] { exit 0 }; foo; print after");
+    assert!(!actual.out.contains("after"));
+}
+
+#[test]
+fn exit_bypasses_catch() {
+    let actual = nu!("try { exit 0 } catch { print caught }; print after");
+    assert!(!actual.out.contains("caught"));
+    assert!(!actual.out.contains("after"));
+}
@@ -525,3 +525,19 @@ mod external_command_arguments {
         assert_eq!(actual.out, "a;&$(hello)");
     }
 }
+
+#[test]
+fn large_external_output_in_subexpression_does_not_hang() {
+    // the exit code must not be awaited while stdout is still unconsumed, or
+    // any output larger than the pipe and channel buffers deadlocks the shell
+    let actual = nu!("let x = (nu --testbin repeater a 100000); $x | str length");
+
+    assert_eq!(actual.out, "100000");
+}
+
+#[test]
+fn failing_external_in_subexpression_surfaces_exit_code_when_collected() {
+    let actual = nu!("let x = (nu --testbin fail | complete); $x.exit_code");
+
+    assert_eq!(actual.out, "1");
+}